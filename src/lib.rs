@@ -1,4 +1,4 @@
-use std::{cell::{Cell, RefCell}, rc::Rc};
+use std::{cell::{Cell, Ref, RefCell}, cmp::Reverse, collections::{BTreeMap, BinaryHeap, HashMap, VecDeque}, rc::Rc, sync::{Arc, Mutex, MutexGuard}, time::{Duration, Instant}};
 
 use rust_decimal::Decimal;
 
@@ -6,7 +6,7 @@ use rust_decimal::Decimal;
 mod tests {
     use rust_decimal::Decimal;
 
-    use crate::{AssetPool, Asset};
+    use crate::{AssetPool, SyncAssetPool, MutationPolicy, Asset, MutateError, MutationLimits, AssetId, AssetCapture, ReloadError, MutatorPool, MutatorBase, StandardMutator, PercentageMutator, CompoundMutator, BackwardMutator, ConditionalMutator, TransferMutator, ScaledMutator, RangeMutator, StepMutator, SetMutator, TieredMutator, CappedMutator, AccrualMutator, OneShotMutator, FixedScheduleMutator, WithdrawalMutator, CompositeMutator, Modeller, Mutator, ProjectionError, BuildError, Event, TriggerError, MutatorVariant, EventStream};
 
     #[test]
     fn asset_pool_changes() {
@@ -15,7 +15,7 @@ mod tests {
         let idx = asset_pool.load(Asset::new(Decimal::new(5090, 2)));
         
         if !(asset_pool.mutate(idx, Decimal::new(-90, 2))) {
-            panic!("Failed to mutate asset {}!", idx);
+            panic!("Failed to mutate asset {:?}!", idx);
         }
 
         let actual = asset_pool.get(idx);
@@ -24,11 +24,206 @@ mod tests {
         assert_eq!(actual, expected)
     }
 
+    #[test]
+    fn asset_mutate_checked() {
+        let asset = Asset::new(Decimal::new(100, 0));
+        let huge = Decimal::new(1_000_000_000, 0);
+        let limits = MutationLimits::new(Some(12), Some(huge));
+
+        assert_eq!(asset.mutate_checked(Decimal::new(2550, 2), &limits), Ok(()));
+        assert_eq!(asset.value.get(), Decimal::new(12550, 2));
+
+        let garbage = Decimal::from_f64_retain(0.1).unwrap();
+        assert_eq!(asset.mutate_checked(garbage, &limits), Err(MutateError::ScaleTooLarge(garbage.scale())));
+        assert_eq!(asset.mutate_checked(garbage, &MutationLimits::default()), Ok(()));
+        asset.value.set(Decimal::new(12550, 2));
+
+        assert_eq!(asset.mutate_checked(huge, &limits), Err(MutateError::MagnitudeTooLarge(huge)));
+        assert_eq!(
+            asset.mutate_checked(huge - Decimal::ONE, &limits), 
+            Err(MutateError::MagnitudeTooLarge(huge + Decimal::new(12450, 2)))
+        );
+        assert_eq!(asset.value.get(), Decimal::new(12550, 2));
+    }
+
+    #[test]
+    fn asset_pool_limits() {
+        let asset_pool = AssetPool::from_values([Decimal::new(100, 0)]);
+        let garbage = Decimal::from_f64_retain(0.1).unwrap();
+
+        asset_pool.set_limits(MutationLimits::new(Some(4), Some(Decimal::new(1000, 0))));
+
+        assert!(!asset_pool.mutate_raw(0, garbage));
+        assert_eq!(asset_pool.mutate_checked(AssetId(0), garbage), Err(MutateError::ScaleTooLarge(garbage.scale())));
+        assert_eq!(
+            asset_pool.mutate_checked(AssetId(0), Decimal::new(900, 0)), 
+            Err(MutateError::MagnitudeTooLarge(Decimal::new(1000, 0)))
+        );
+        assert_eq!(asset_pool.mutate_checked(AssetId(1), Decimal::ONE), Err(MutateError::AssetNotFound(1)));
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(100, 0)));
+
+        assert!(asset_pool.mutate_raw(0, Decimal::new(-10025, 2)));
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(-25, 2)));
+
+        asset_pool.set_policy(MutationPolicy::ErrorOnNegative);
+        assert_eq!(asset_pool.mutate_checked(AssetId(0), Decimal::NEGATIVE_ONE), Err(MutateError::Rejected));
+    }
+
+    #[test]
+    fn asset_pool_limits_with_scale() {
+        let asset_pool = AssetPool::from_values([Decimal::new(100001, 2)]);
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.set_scale(Some(2));
+        asset_pool.set_limits(MutationLimits::new(Some(2), None));
+        mutator_pool.load(Box::new(PercentageMutator::new(MutatorBase::new(
+            0, 0, Decimal::new(5, 2), Decimal::ZERO, true, 10, 0
+        ))));
+
+        // Each change of 5% has four decimal places until rounded to the scale.
+        let modeller = Modeller::new(asset_pool, mutator_pool);
+        let result = modeller.project(0, 20, 1, 0, None).unwrap();
+
+        assert_eq!(result.final_values(), vec![Decimal::new(110251, 2)]);
+    }
+
+    #[test]
+    fn asset_pool_load_batch() {
+        let asset_pool = AssetPool::from_values([Decimal::ONE]);
+
+        let range = asset_pool.load_batch([Decimal::TWO, Decimal::TEN].map(Asset::new));
+        assert_eq!(range, 1..3);
+        assert_eq!(asset_pool.sum_range(range), Some(Decimal::new(12, 0)));
+
+        assert_eq!(asset_pool.load_batch(Vec::new()), 3..3);
+    }
+
+    #[test]
+    fn asset_pool_get_or_insert_with() {
+        let asset_pool = AssetPool::from_values([Decimal::ONE]);
+
+        assert_eq!(asset_pool.get_or_insert_with(0, || Decimal::TEN), Decimal::ONE);
+        assert_eq!(asset_pool.get_or_insert_with(3, || Decimal::TEN), Decimal::TEN);
+        assert_eq!(asset_pool.get_or_insert_with(3, || Decimal::TWO), Decimal::TEN);
+
+        let values: Vec<Decimal> = asset_pool.iter_values().collect();
+        assert_eq!(values, vec![Decimal::ONE, Decimal::ZERO, Decimal::ZERO, Decimal::TEN]);
+        assert!(AssetPool::reload(asset_pool.capture()).is_ok());
+    }
+
+    #[test]
+    fn asset_pool_try_load() {
+        let asset_pool = AssetPool::new();
+        let bounds = || Decimal::new(-1_000_000_000_000, 0)..=Decimal::new(1_000_000_000_000, 0);
+
+        let idx = asset_pool.try_load(Asset::new(Decimal::new(5090, 2)), bounds()).unwrap();
+        assert_eq!(asset_pool.get(idx), Some(Decimal::new(5090, 2)));
+
+        let too_large = Decimal::new(2_000_000_000_000, 0);
+        assert_eq!(asset_pool.try_load(Asset::new(too_large), bounds()), Err(too_large));
+        assert_eq!(asset_pool.len(), 1);
+    }
+
+    #[test]
+    fn asset_pool_apply() {
+        let asset_pool = AssetPool::with_policy(MutationPolicy::ErrorOnNegative);
+        let idx = asset_pool.load(Asset::new(Decimal::new(2545, 2)));
+
+        assert!(asset_pool.apply(idx, |v| v * Decimal::TWO));
+        assert_eq!(asset_pool.get(idx), Some(Decimal::new(5090, 2)));
+
+        assert!(!asset_pool.apply(idx, |v| -v));
+        assert!(!asset_pool.apply(AssetId(1), |v| v));
+        assert_eq!(asset_pool.get(idx), Some(Decimal::new(5090, 2)));
+
+        assert_eq!(asset_pool.replace(idx, Decimal::NEGATIVE_ONE), Some(Decimal::new(5090, 2)));
+        assert_eq!(asset_pool.get(idx), Some(Decimal::NEGATIVE_ONE));
+        assert_eq!(asset_pool.replace(AssetId(1), Decimal::ONE), None);
+    }
+
+    #[test]
+    fn asset_pool_scale() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(5000, 2)));
+        mutator_pool.load(Box::new(PercentageMutator::new(MutatorBase::new(
+            0, 0, Decimal::new(1799, 6), Decimal::ZERO, true, 10, 0
+        ))));
+
+        let unrounded = asset_pool.fork();
+        Event::new(0, 0, 0).trigger(&unrounded, &mutator_pool).unwrap();
+        assert_eq!(unrounded.get_raw(0), Some(Decimal::new(5008995, 5)));
+
+        asset_pool.set_scale(Some(2));
+        Event::new(0, 0, 0).trigger(&asset_pool, &mutator_pool).unwrap();
+
+        let value = asset_pool.get_raw(0).unwrap();
+        assert_eq!(value, Decimal::new(5009, 2));
+        assert_eq!(value.scale(), 2);
+        assert_eq!(value.to_string(), "50.09");
+    }
+
+    #[test]
+    fn asset_pool_tags() {
+        let asset_pool = AssetPool::from_values([
+            Decimal::new(100, 0), 
+            Decimal::new(250, 0), 
+            Decimal::new(-40, 0), 
+            Decimal::new(60, 0)
+        ]);
+
+        assert!(asset_pool.tag(0, "cash"));
+        assert!(asset_pool.tag(3, "cash"));
+        assert!(asset_pool.tag(3, "cash"));
+        assert!(asset_pool.tag(1, "equity"));
+        assert!(asset_pool.tag(2, "debt"));
+        assert!(!asset_pool.tag(4, "debt"));
+
+        assert_eq!(asset_pool.value_of_tag("cash"), Decimal::new(160, 0));
+        assert_eq!(asset_pool.value_of_tag("equity"), Decimal::new(250, 0));
+        assert_eq!(asset_pool.value_of_tag("property"), Decimal::ZERO);
+
+        asset_pool.remove(0);
+        assert_eq!(asset_pool.value_of_tag("cash"), Decimal::new(60, 0));
+
+        asset_pool.remove_stable(1);
+        assert_eq!(asset_pool.value_of_tag("cash"), Decimal::new(60, 0));
+        assert_eq!(asset_pool.value_of_tag("debt"), Decimal::new(-40, 0));
+        assert_eq!(asset_pool.value_of_tag("equity"), Decimal::ZERO);
+    }
+
+    #[test]
+    fn asset_pool_merge() {
+        let asset_pool = AssetPool::from_values([Decimal::ONE, Decimal::TWO]);
+        let other = AssetPool::from_values([Decimal::TEN, Decimal::ONE_HUNDRED]);
+
+        assert_eq!(asset_pool.merge(&other), vec![2, 3]);
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::ONE));
+        assert_eq!(asset_pool.get_raw(3), Some(Decimal::ONE_HUNDRED));
+        assert_eq!(other.len(), 2);
+
+        assert_eq!(asset_pool.merge(&asset_pool), vec![4, 5, 6, 7]);
+        assert_eq!(asset_pool.get_raw(6), Some(Decimal::TEN));
+    }
+
+    #[test]
+    fn asset_pool_diff() {
+        let asset_pool = AssetPool::from_values([Decimal::new(100, 0), Decimal::new(250, 0)]);
+        let before = asset_pool.capture();
+
+        asset_pool.mutate_raw(1, Decimal::new(-30, 0));
+        let after = asset_pool.capture();
+
+        assert_eq!(AssetPool::diff(&before, &after), vec![(1, Decimal::new(-30, 0))]);
+        assert!(AssetPool::diff(&after, &after).is_empty());
+    }
+
     #[test]
     fn asset_pool_capture() {
         let asset_pool = AssetPool::new();
 
-        let values = vec![ 
+        let values = [ 
             Decimal::new(42, 3), 
             Decimal::new(900, 0), 
             Decimal::new(500, 1) 
@@ -46,224 +241,5980 @@ mod tests {
 
         
     }
-}
 
-#[derive(PartialEq, Eq)]
-pub struct AssetCapture {
-    value: Decimal,
-    idx: usize,
-}
+    #[test]
+    fn asset_pool_capture_into() {
+        let values = [ 
+            Decimal::new(42, 3), 
+            Decimal::new(900, 0), 
+            Decimal::new(500, 1) 
+        ];
 
-impl Ord for AssetCapture {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.value.cmp(&other.value)
-    }
-}
+        let asset_pool = AssetPool::from_values(values);
+        let mut buf = asset_pool.capture();
 
-impl PartialOrd for AssetCapture {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+        asset_pool.remove(0);
+        asset_pool.capture_into(&mut buf);
+
+        assert_eq!(buf.len(), 2);
+        assert_eq!(buf[0].value, values[2]);
     }
-}
 
-pub struct AccountCapture {
-    asset_id: Vec<usize>,
-    idx: usize
-}
+    #[test]
+    fn asset_pool_capture_subset() {
+        let values = [ 
+            Decimal::new(42, 3), 
+            Decimal::new(900, 0), 
+            Decimal::new(500, 1) 
+        ];
 
-pub struct Account {
-    idx: usize,
-    asset_ids: Vec<usize>,
-    asset_pool: Rc<AssetPool>
-}
+        let asset_pool = AssetPool::from_values(values);
 
-impl Account {
-    pub fn new(idx: usize, asset_pool: Rc<AssetPool>) -> Account {
-        Account { idx, asset_ids: vec![], asset_pool }
+        let subset = asset_pool.capture_subset(&[2, 0]).unwrap();
+        assert_eq!((subset[0].idx, subset[0].value), (2, values[2]));
+        assert_eq!((subset[1].idx, subset[1].value), (0, values[0]));
+        assert!(asset_pool.capture_subset(&[0, 3]).is_none());
     }
 
-    pub fn total_value(&self) -> Decimal {
-        self.asset_ids
-            .iter()
-            .fold(Decimal::ZERO, |accum, next_id| {
-                accum + self.asset_pool
-                    .get(*next_id)
-                    .unwrap_or(Decimal::ZERO)
-            })
-    }
-}
+    #[test]
+    fn asset_pool_capture_order() {
+        let asset_pool = AssetPool::from_values([
+            Decimal::new(30, 0), 
+            Decimal::new(10, 0), 
+            Decimal::new(20, 0), 
+            Decimal::new(10, 0)
+        ]);
+        asset_pool.remove(1);
 
-pub struct Asset {
-    value: Cell<Decimal>,
-}
+        let captures = asset_pool.capture();
 
-impl Asset {
-    pub fn new(value: Decimal) -> Asset {
-        Asset { value: Cell::new(value) }
-    }
+        assert_eq!(captures.len(), 3);
+        for (position, cap) in captures.iter().enumerate() {
+            assert_eq!(cap.idx, position);
+        }
 
-    pub fn mutate(&self, amount: Decimal) {
-        self.value.set(self.value.get() + amount);
+        let reloaded = AssetPool::reload_unchecked(captures);
+        assert_eq!(reloaded.get_many(&[0, 1, 2]), asset_pool.get_many(&[0, 1, 2]));
     }
-}
 
-pub struct AssetPool {
-    assets: RefCell<Vec<Asset>>
-}
+    #[test]
+    fn asset_capture_identity() {
+        let first = AssetCapture { value: Decimal::ONE, idx: 0 };
+        let second = AssetCapture { value: Decimal::ONE, idx: 1 };
 
-impl AssetPool {
-    pub fn new() -> Rc<AssetPool> {
-        Rc::new(AssetPool { assets: RefCell::new(Vec::new()) })
-    }
+        assert_ne!(first, second);
+        assert!(first < second);
 
-    pub fn load(&self, asset: Asset) -> usize {
-        let mut assets = self.assets.borrow_mut();
-        assets.push(asset);
+        let set: std::collections::HashSet<AssetCapture> = [first, second, first].into_iter().collect();
+        assert_eq!(set.len(), 2);
 
-        assets.len() - 1
+        let updated = AssetCapture { value: Decimal::TEN, idx: 1 };
+        let merged: std::collections::HashMap<usize, AssetCapture> = [first, second, updated]
+            .into_iter()
+            .map(|cap| (cap.idx(), cap))
+            .collect();
+        assert_eq!(merged[&1].value(), Decimal::TEN);
     }
 
-    pub fn get(&self, idx: usize) -> Option<Decimal> {
-        if let Some(asset) = self.assets.borrow().get(idx) {
-            Some(asset.value.get())
-        } else { None }
+    #[test]
+    fn asset_pool_reload() {
+        let captures = vec![
+            AssetCapture { value: Decimal::new(1, 0), idx: 1 },
+            AssetCapture { value: Decimal::new(2, 0), idx: 0 },
+        ];
+
+        let asset_pool = AssetPool::reload(captures).unwrap();
+
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(2, 0)));
+        assert_eq!(asset_pool.get_raw(1), Some(Decimal::new(1, 0)));
+
+        let captures = vec![
+            AssetCapture { value: Decimal::new(1, 0), idx: 0 },
+            AssetCapture { value: Decimal::new(2, 0), idx: 2 },
+        ];
+
+        assert_eq!(
+            AssetPool::reload(captures).err(), 
+            Some(ReloadError::NonContiguous { expected: 1, found: 2 })
+        );
     }
 
-    pub unsafe fn get_unchecked(&self, idx: usize) -> Decimal {
-        self.assets.borrow()
-        .get_unchecked(idx)
-        .value
-        .get()
+    #[test]
+    fn asset_pool_remove() {
+        let asset_pool = AssetPool::new();
+
+        for i in 0..4 {
+            asset_pool.load(Asset::new(Decimal::new(i, 0)));
+        }
+
+        assert!(asset_pool.remove(0).is_some());
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(3, 0)));
+        assert_eq!(asset_pool.get_raw(3), None);
+
+        let (asset, remap) = asset_pool.remove_stable(0).unwrap();
+        assert_eq!(asset.value.get(), Decimal::new(3, 0));
+        assert_eq!(remap, vec![(1, 0), (2, 1)]);
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(1, 0)));
+        assert_eq!(asset_pool.get_raw(1), Some(Decimal::new(2, 0)));
+
+        assert!(asset_pool.remove(2).is_none());
+        assert!(asset_pool.remove_stable(2).is_none());
     }
 
-    pub fn mutate(&self, idx: usize, change: Decimal) -> bool {
-        if let Some(asset) = self.assets.borrow().get(idx) {
-            asset.mutate(change);
-            true
-        } else { false }
+    #[test]
+    fn asset_pool_iter_values() {
+        let asset_pool = AssetPool::new();
+        assert!(asset_pool.is_empty());
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        asset_pool.load(Asset::new(Decimal::new(250, 1)));
+
+        assert_eq!(asset_pool.len(), 2);
+        assert_eq!(asset_pool.iter_values().sum::<Decimal>(), Decimal::new(1250, 1));
     }
 
-    pub unsafe fn mutate_unchecked(&self, idx: usize, change: Decimal) {
-        self.assets.borrow().get_unchecked(idx).mutate(change)
+    #[test]
+    fn sync_asset_pool() {
+        let asset_pool = SyncAssetPool::new();
+        let idx = asset_pool.load(Asset::new(Decimal::ZERO));
+
+        let handles: Vec<_> = (0..4).map(|_| {
+            let asset_pool = asset_pool.clone();
+            std::thread::spawn(move || {
+                for _ in 0..100 {
+                    asset_pool.mutate(idx, Decimal::ONE);
+                }
+            })
+        }).collect();
+
+        handles.into_iter().for_each(|handle| handle.join().unwrap());
+
+        assert_eq!(asset_pool.get(idx), Some(Decimal::new(400, 0)));
+        assert_eq!(asset_pool.get_raw(1), None);
+        assert!(!asset_pool.mutate_raw(1, Decimal::ONE));
+        assert_eq!(asset_pool.capture()[0].value, Decimal::new(400, 0));
     }
 
-    /// Removes and returns the assets from the `AssetPool`.
-    /// The `AssetPool`'s assets are replaced with an empty vector.
-    pub fn unload(&self) -> Vec<Asset> {
-        self.assets.replace(Vec::new())
+    #[test]
+    fn asset_pool_statistics() {
+        let asset_pool = AssetPool::new();
+        assert_eq!(asset_pool.mean_value(), None);
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        asset_pool.load(Asset::new(Decimal::new(-20, 0)));
+        asset_pool.load(Asset::new(Decimal::new(40, 0)));
+
+        assert_eq!(asset_pool.total_value(), Decimal::new(120, 0));
+        assert_eq!(asset_pool.min_value(), Some(Decimal::new(-20, 0)));
+        assert_eq!(asset_pool.max_value(), Some(Decimal::new(100, 0)));
+        assert_eq!(asset_pool.mean_value(), Some(Decimal::new(40, 0)));
     }
 
-    /// Creates captures of all assets owned by the given `AssetPool`.
-    /// The given `AssetPool` retains all of its assets.
-    pub fn capture(&self) -> Vec<AssetCapture> {
-        let assets = self.assets.borrow();
-        let mut out = Vec::new();
+    #[test]
+    fn asset_pool_mutation_policy() {
+        let asset_pool = AssetPool::new();
+        let idx = asset_pool.load(Asset::new(Decimal::new(10, 0)));
 
-        for idx in 0..assets.len() {
-            out.push( AssetCapture { value: unsafe {
-                assets.get_unchecked(idx).value.get()
-            }, idx } );
-        }
+        assert!(asset_pool.mutate(idx, Decimal::new(-15, 0)));
+        assert_eq!(asset_pool.get(idx), Some(Decimal::new(-5, 0)));
 
-        out
+        let asset_pool = AssetPool::with_policy(MutationPolicy::ClampAtZero);
+        let idx = asset_pool.load(Asset::new(Decimal::new(10, 0)));
+
+        assert!(asset_pool.mutate(idx, Decimal::new(-15, 0)));
+        assert_eq!(asset_pool.get(idx), Some(Decimal::ZERO));
+
+        let asset_pool = AssetPool::with_policy(MutationPolicy::ErrorOnNegative);
+        let idx = asset_pool.load(Asset::new(Decimal::new(10, 0)));
+
+        assert!(!asset_pool.mutate(idx, Decimal::new(-15, 0)));
+        assert_eq!(asset_pool.get(idx), Some(Decimal::new(10, 0)));
+        assert!(asset_pool.mutate(idx, Decimal::new(-10, 0)));
+        assert_eq!(asset_pool.get(idx), Some(Decimal::ZERO));
     }
 
-    /// Sorts the given captures by idx, and then converts all
-    /// captures into assets, which are then given to the returned
-    /// `AssetPool`.
-    pub fn reload(mut captures: Vec<AssetCapture>) -> Rc<AssetPool> {
-        captures.sort_unstable();
+    #[test]
+    fn asset_pool_mutate_many() {
+        let asset_pool = AssetPool::with_policy(MutationPolicy::ErrorOnNegative);
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        asset_pool.load(Asset::new(Decimal::new(50, 0)));
 
-        AssetPool::reload_unchecked(captures)
+        assert_eq!(asset_pool.mutate_many(&[(0, Decimal::new(-30, 0)), (1, Decimal::new(30, 0))]), Ok(()));
+        assert_eq!(asset_pool.get_many(&[0, 1]), Some(vec![Decimal::new(70, 0), Decimal::new(80, 0)]));
+
+        assert_eq!(asset_pool.mutate_many(&[(0, Decimal::ONE), (2, Decimal::ONE)]), Err(2));
+        assert_eq!(asset_pool.mutate_many(&[(1, Decimal::new(-50, 0)), (1, Decimal::new(-50, 0))]), Err(1));
+        assert_eq!(asset_pool.get_many(&[0, 1]), Some(vec![Decimal::new(70, 0), Decimal::new(80, 0)]));
+
+        // Overflow and the change limits reject the batch rather than panicking.
+        assert_eq!(asset_pool.mutate_many(&[(1, Decimal::ONE), (0, Decimal::MAX)]), Err(0));
+        asset_pool.set_limits(MutationLimits::new(Some(0), Some(Decimal::new(100, 0))));
+        assert_eq!(asset_pool.mutate_many(&[(0, Decimal::ONE), (1, Decimal::new(-100, 0))]), Err(1));
+        assert_eq!(asset_pool.mutate_many(&[(0, Decimal::ONE), (1, Decimal::new(5, 1))]), Err(1));
+        assert_eq!(asset_pool.get_many(&[0, 1]), Some(vec![Decimal::new(70, 0), Decimal::new(80, 0)]));
     }
 
-    /// Converts all captures into assets, which are then given
-    /// to the returned `AssetPool`.
-    /// 
-    /// **Warning:** if captures are not sorted by idx, the returned
-    /// `AssetPool` will not function properly
-    pub fn reload_unchecked(captures: Vec<AssetCapture>) -> Rc<AssetPool> {
-        let out = AssetPool::new();
+    #[test]
+    fn asset_pool_scale_all() {
+        let asset_pool = AssetPool::with_policy(MutationPolicy::ErrorOnNegative);
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        asset_pool.load(Asset::new(Decimal::new(5, 0)));
 
-        captures.into_iter().for_each(|cap| {
-            out.load(Asset::new(cap.value));
-        });
+        asset_pool.scale_all(Decimal::new(125, 2));
+        assert_eq!(asset_pool.read_snapshot(), vec![Decimal::new(125, 0), Decimal::new(625, 2)]);
 
-        out
+        asset_pool.offset_all(Decimal::new(-10, 0));
+        assert_eq!(asset_pool.read_snapshot(), vec![Decimal::new(115, 0), Decimal::new(-375, 2)]);
+
+        asset_pool.set_scale(Some(1));
+        asset_pool.scale_all(Decimal::new(1, 1));
+        assert_eq!(asset_pool.read_snapshot(), vec![Decimal::new(115, 1), Decimal::new(-4, 1)]);
     }
-}
 
-pub struct MutatorBaseCapture {
-    total_change: Decimal,
-    idx: usize
-}
+    #[test]
+    fn asset_pool_fork() {
+        let asset_pool = AssetPool::from_values([Decimal::ONE, Decimal::TWO]);
+        let fork = asset_pool.fork();
 
-impl MutatorBaseCapture {
-    fn capture(base: &MutatorBase) -> MutatorBaseCapture {
-        MutatorBaseCapture { total_change: base.total_change, idx: base.idx }
+        fork.mutate_raw(0, Decimal::TEN);
+
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::ONE));
+        assert_eq!(fork.get_many(&[0, 1]), Some(vec![Decimal::new(11, 0), Decimal::TWO]));
     }
-}
 
-pub struct MutatorBase {
-    pub idx: usize,
-    pub target_idx: usize,
-    pub change: Decimal,
-    pub total_change: Decimal,
-    pub is_add: bool,
-    pub cycle: u32,
-    cycle_reciprocal: f64,
-    pub unix_reference: u64
-}
+    #[cfg(feature = "csv")]
+    #[test]
+    fn asset_pool_csv() {
+        let asset_pool = AssetPool::from_values([Decimal::new(5090, 2), Decimal::new(-3, 0)]);
 
-impl MutatorBase {
-    pub fn new(idx: usize, target_idx: usize, change: Decimal, 
-        total_change: Decimal, is_add: bool, cycle: u32, unix_reference: u64) 
-            -> MutatorBase 
-    {
-        let cycle_reciprocal = (1 as f64) / (cycle as f64);
-        
-        MutatorBase { idx, target_idx, change, total_change, is_add, cycle, cycle_reciprocal, unix_reference }
+        let mut out = Vec::new();
+        asset_pool.to_csv(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out.clone()).unwrap(), "0,50.90\n1,-3\n");
+
+        let reloaded = AssetPool::from_csv(out.as_slice()).unwrap();
+        assert_eq!(reloaded.get_many(&[0, 1]), Some(vec![Decimal::new(5090, 2), Decimal::new(-3, 0)]));
+
+        let shuffled = AssetPool::from_csv("1,2\n0,1\n".as_bytes()).unwrap();
+        assert_eq!(shuffled.get_many(&[0, 1]), Some(vec![Decimal::ONE, Decimal::TWO]));
+
+        assert!(matches!(
+            AssetPool::from_csv("0,1\n2,2\n".as_bytes()),
+            Err(crate::CsvError::Reload(ReloadError::NonContiguous { expected: 1, found: 2 }))
+        ));
+        assert!(matches!(
+            AssetPool::from_csv("0,abc\n".as_bytes()), 
+            Err(crate::CsvError::Parse { line: 1 })
+        ));
     }
 
-    pub fn projection_length(&self, unix_initial_event: u64) -> u64 {
-        
-        ((unix_initial_event - (unix_initial_event % self.cycle as u64)) as f64 * self.cycle_reciprocal) as u64 + 1
+    #[test]
+    fn asset_pool_value_of_group() {
+        let asset_pool = AssetPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        asset_pool.load(Asset::new(Decimal::new(250, 1)));
+        asset_pool.load(Asset::new(Decimal::new(-40, 0)));
+
+        assert_eq!(asset_pool.value_of_group(&[0]), Some(Decimal::new(100, 0)));
+        assert_eq!(asset_pool.value_of_group(&[0, 2]), Some(Decimal::new(60, 0)));
+        assert_eq!(asset_pool.value_of_group(&[1, 3]), None);
     }
 
-    pub fn unix_initial_event(&self, start: u64) -> u64 {
-        let cycle64 = self.cycle as u64;
-        let mut ur_cpy = self.unix_reference;
-        let top = start + cycle64;
-        let bottom = start - cycle64;
+    #[test]
+    fn asset_pool_get_many() {
+        let asset_pool = AssetPool::from_values([Decimal::ONE, Decimal::TWO, Decimal::TEN]);
 
-        if self.unix_reference != start {
-            while cycle64 > top || cycle64 < bottom {
-                ur_cpy += cycle64;
-            }
-        };
+        assert_eq!(asset_pool.get_many(&[2, 0]), Some(vec![Decimal::TEN, Decimal::ONE]));
+        assert_eq!(asset_pool.get_many(&[0, 3]), None);
+    }
+
+    #[test]
+    fn asset_pool_read_snapshot() {
+        let asset_pool = AssetPool::from_values([Decimal::ONE, Decimal::TWO]);
 
-        if ur_cpy >= start {
-            return ur_cpy;
+        for value in asset_pool.read_snapshot() {
+            asset_pool.load(Asset::new(value * Decimal::TEN));
         }
 
-        ur_cpy + cycle64
+        assert_eq!(asset_pool.read_snapshot(), vec![
+            Decimal::ONE, 
+            Decimal::TWO, 
+            Decimal::TEN, 
+            Decimal::new(20, 0)
+        ]);
     }
-}
 
-pub trait Mutator {
-    fn on_event(&self, amount: Decimal) -> Decimal;
-    fn capture(&self) -> String;
-    fn reset(&mut self, capture: String);
-}
+    #[test]
+    fn asset_pool_sum_range() {
+        let asset_pool = AssetPool::from_values([
+            Decimal::new(100, 0), 
+            Decimal::new(250, 1), 
+            Decimal::new(-40, 0)
+        ]);
 
-pub struct EventMemento {
-    time_pos: u64,
-    account_states: Vec<AccountCapture>,
-    mutator_states: Vec<String>
-}
+        assert_eq!(asset_pool.sum_range(1..3), Some(Decimal::new(-150, 1)));
+        assert_eq!(asset_pool.sum_range(0..3), asset_pool.value_of_group(&[0, 1, 2]));
+        assert_eq!(asset_pool.sum_range(2..2), Some(Decimal::ZERO));
+        assert_eq!(asset_pool.sum_range(2..4), None);
+    }
 
+    #[test]
+    fn unix_initial_event() {
+        let base = |unix_reference| MutatorBase::new(
+            0, 0, Decimal::ONE, Decimal::ZERO, true, 7, unix_reference
+        );
+
+        assert_eq!(base(10).unix_initial_event(50), 52);
+        assert_eq!(base(8).unix_initial_event(50), 50);
+        assert_eq!(base(50).unix_initial_event(50), 50);
+        assert_eq!(base(60).unix_initial_event(50), 60);
+
+        let offset = MutatorBase::with_offset(0, 0, Decimal::ONE, Decimal::ZERO, true, 30, 0, 15);
+
+        assert_eq!(offset.unix_initial_event(0), 15);
+        assert_eq!(offset.unix_initial_event(20), 45);
+        assert_eq!(offset.unix_initial_event(45), 45);
+
+        let relative = MutatorBase::new(0, 0, Decimal::ONE, Decimal::ZERO, true, 30, 0);
+
+        assert_eq!(relative.unix_initial_event(5), 30);
+        assert_eq!(relative.create_events(5, 100, 0).len(), 3);
+        assert_eq!(relative.unix_initial_event(u64::MAX - 5), u64::MAX);
+        assert!(relative.create_events(u64::MAX - 5, u64::MAX - 1, 0).is_empty());
+    }
+
+    #[test]
+    fn projection_length() {
+        let base = |cycle| MutatorBase::new(0, 0, Decimal::ONE, Decimal::ZERO, true, cycle, 0);
+        let near = 1u64 << 53;
+
+        assert_eq!(base(7).projection_length(near - 3, near + 18), 4);
+        assert_eq!(base(7).projection_length(near + 1, near + 1), 1);
+        assert_eq!(base(7).projection_length(near + 1, near), 0);
+
+        // (2^60 + 7) as f64 rounds to 2^60, which undercounted by 7
+        assert_eq!(base(1).projection_length(0, (1 << 60) + 7), (1 << 60) + 8);
+        assert_eq!(base(3).projection_length(u64::MAX - 9, u64::MAX), 4);
+    }
+
+    #[test]
+    fn mutator_base_builder() {
+        let base = MutatorBase::builder()
+            .idx(1)
+            .target_idx(2)
+            .change(Decimal::ONE)
+            .cycle(7)
+            .unix_reference(10)
+            .build()
+            .unwrap();
+
+        assert_eq!((base.idx, base.target_idx, base.cycle, base.unix_reference), (1, 2, 7, 10));
+        assert_eq!(base.total_change.get(), Decimal::ZERO);
+        assert_eq!(base.unix_initial_event(50), 52);
+
+        assert_eq!(MutatorBase::builder().build().err(), Some(BuildError::ZeroCycle));
+
+        let mutator = StandardMutator(MutatorBase::builder()
+            .name("Monthly Salary")
+            .cycle(30)
+            .build()
+            .unwrap());
+
+        assert_eq!(mutator.name(), Some("Monthly Salary"));
+        assert!(format!("{:?}", mutator.0).contains("Monthly Salary"));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn schedule() {
+        use chrono::TimeZone;
+        use crate::Schedule;
+
+        let start = chrono::Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+        let end = chrono::Utc.with_ymd_and_hms(2024, 4, 30, 0, 0, 0).unwrap();
+
+        let times: Vec<u64> = Schedule::Monthly.events(start, end, 0, 0)
+            .iter()
+            .map(|event| event.time_pos)
+            .collect();
+
+        let expected: Vec<u64> = [(1, 31), (2, 29), (3, 31), (4, 30)]
+            .iter()
+            .map(|(m, d)| chrono::Utc.with_ymd_and_hms(2024, *m, *d, 0, 0, 0).unwrap().timestamp() as u64)
+            .collect();
+
+        assert_eq!(times, expected);
+
+        let mut base = MutatorBase::new(0, 0, Decimal::ONE, Decimal::ZERO, true, 1, 0);
+
+        assert!(!Schedule::Monthly.lower_into(&mut base, start));
+        assert!(Schedule::Weekly.lower_into(&mut base, start));
+        assert_eq!(base.cycle, 604800);
+        assert_eq!(base.unix_reference, start.timestamp() as u64);
+    }
+
+    #[test]
+    fn projection() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        let asset_idx = asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, asset_idx.raw(), Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+
+        let modeller = Modeller::new(asset_pool, mutator_pool);
+        let result = modeller.project(0, 30, 2, 0, None).unwrap();
+
+        assert_eq!(result.interval_points.len(), 2);
+        assert_eq!(result.interval_points[0].asset_captures[0].value, Decimal::new(130, 0));
+        assert_eq!(result.interval_points[1].asset_captures[0].value, Decimal::new(160, 0));
+
+        assert_eq!(result.event_mementos.len(), 2);
+        assert_eq!(result.event_mementos[1].time_pos, 30);
+
+        assert_eq!(result.metrics().events_generated(), 6);
+        assert_eq!(result.metrics().events_triggered(), 6);
+        assert_eq!(result.metrics().peak_events(), 3);
+        assert_eq!(result.metrics().intervals(), 2);
+        assert_eq!(result.event_mementos[1].asset_captures[0].value, Decimal::new(130, 0));
+
+        assert_eq!(result.asset_value_at(0, 0), Some(Decimal::new(130, 0)));
+        assert_eq!(result.asset_value_at(2, 0), None);
+        assert_eq!(result.final_values(), vec![Decimal::new(160, 0)]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn result_packet_serde() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(5090, 2)));
+        mutator_pool.load(Box::new(PercentageMutator::new(MutatorBase::new(
+            0, 0, Decimal::new(1, 1), Decimal::ZERO, true, 5, 0
+        ))));
+
+        let result = Modeller::new(asset_pool, mutator_pool).project(0, 10, 1, 0, None).unwrap();
+
+        let json = serde_json::to_string(&result).unwrap();
+        let reloaded: crate::ResultPacket = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            reloaded.interval_points[0].asset_captures[0].value,
+            result.interval_points[0].asset_captures[0].value
+        );
+        assert_eq!(reloaded.interval_points[0].mutator_captures[0].variant, MutatorVariant::Percentage);
+    }
+
+    #[test]
+    fn modeller_snapshot() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+
+        let mut modeller = Modeller::new(asset_pool, mutator_pool);
+        modeller.add_event(Event::new(5, 0, 0));
+        modeller.project(0, 30, 1, 0, None).unwrap();
+
+        let snapshot = modeller.snapshot();
+
+        #[cfg(feature = "serde")]
+        let snapshot: crate::ModellerSnapshot = 
+            serde_json::from_str(&serde_json::to_string(&snapshot).unwrap()).unwrap();
+
+        let factory = |capture: &crate::MutatorCapture| -> Option<Box<dyn Mutator>> {
+            match capture.variant() {
+                MutatorVariant::Standard => Some(Box::new(StandardMutator(MutatorBase::new(
+                    0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+                )))),
+                _ => None
+            }
+        };
+
+        let restored = Modeller::restore(snapshot, factory).unwrap();
+        assert_eq!(restored.events, modeller.events);
+        assert_eq!(restored.asset_pool.get_raw(0), Some(Decimal::new(140, 0)));
+        assert_eq!(restored.mutator_pool.total_change(0), Some(Decimal::new(40, 0)));
+
+        let missing = Modeller::restore(modeller.snapshot(), |_| None).err();
+        assert_eq!(missing, Some(crate::RestoreError::MissingMutator(0)));
+    }
+
+    #[test]
+    fn projection_table() {
+        let asset_pool = AssetPool::from_values([Decimal::new(100, 0), Decimal::new(50, 0)]);
+        let mutator_pool = MutatorPool::new();
+
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 1, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+
+        let mut result = Modeller::new(asset_pool, mutator_pool).project(0, 30, 2, 0, None).unwrap();
+
+        let (times, rows) = result.to_table().unwrap();
+        assert_eq!(times, vec![29, 59]);
+        assert_eq!(rows, vec![
+            vec![Decimal::new(100, 0), Decimal::new(80, 0)],
+            vec![Decimal::new(100, 0), Decimal::new(110, 0)]
+        ]);
+
+        result.interval_points[1].asset_captures.pop();
+        assert_eq!(result.to_table(), Err(1));
+    }
+
+    #[test]
+    fn projection_cagr() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(PercentageMutator::new(MutatorBase::with_offset(
+            0, 0, Decimal::new(1, 1), Decimal::ZERO, true, 10, 0, 10
+        ))));
+
+        let result = Modeller::new(asset_pool, mutator_pool).project(0, 21, 1, 0, None).unwrap();
+        let cagr = result.cagr(0, 5).unwrap();
+
+        assert!((cagr - Decimal::new(1, 1)).abs() < Decimal::new(1, 9));
+        assert_eq!(result.cagr(1, 5), None);
+        assert_eq!(result.cagr(0, 0), None);
+    }
+
+    #[test]
+    fn projection_timeseries() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            1, 0, Decimal::new(1, 0), Decimal::ZERO, true, 20, 0
+        ))));
+
+        let mut modeller = Modeller::new(asset_pool, mutator_pool);
+        let coarse = modeller.project(0, 30, 1, 0, None).unwrap();
+
+        assert_eq!(coarse.timeseries(0), vec![
+            (0, Decimal::new(100, 0)), 
+            (29, Decimal::new(132, 0))
+        ]);
+
+        modeller.record_every_event = true;
+        let fine = modeller.project(30, 30, 1, 0, None).unwrap();
+
+        assert_eq!(fine.timeseries(0), vec![
+            (30, Decimal::new(132, 0)), 
+            (40, Decimal::new(142, 0)), 
+            (50, Decimal::new(153, 0)), 
+            (59, Decimal::new(163, 0))
+        ]);
+    }
+
+    #[test]
+    fn projection_resume() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        asset_pool.load(Asset::new(Decimal::new(1000, 0)));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+        mutator_pool.load(Box::new(PercentageMutator::new(MutatorBase::new(
+            1, 1, Decimal::new(1, 2), Decimal::ZERO, true, 15, 5
+        ))));
+
+        let modeller = Modeller::new(asset_pool.clone(), mutator_pool);
+        let full = modeller.project(0, 30, 4, 0, None).unwrap();
+        let expected: Vec<Decimal> = asset_pool.iter_values().collect();
+
+        assert_eq!(full.interval_points().len(), 4);
+        let midpoint = full.into_event_mementos().remove(2);
+        assert_eq!(midpoint.time_pos(), 60);
+
+        let resumed = modeller.project(0, 30, 4, 0, Some(midpoint)).unwrap();
+        let actual: Vec<Decimal> = asset_pool.iter_values().collect();
+
+        assert_eq!(actual, expected);
+        assert_eq!(resumed.interval_points().len(), 2);
+        assert_eq!(resumed.interval_points()[1].time_pos(), 119);
+    }
+
+    #[test]
+    fn mutator_pool_mutators_for() {
+        let mutator_pool = MutatorPool::new();
+
+        for target_idx in [1, 0, 1] {
+            mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+                0, target_idx, Decimal::ONE, Decimal::ZERO, true, 10, 0
+            ))));
+        }
+
+        assert_eq!(mutator_pool.len(), 3);
+        assert!(!mutator_pool.is_empty());
+        assert_eq!(mutator_pool.base_at(1).map(|base| base.target_idx), Some(0));
+        assert!(mutator_pool.base_at(3).is_none());
+
+        assert_eq!(mutator_pool.total_change(2), Some(Decimal::ZERO));
+        assert_eq!(mutator_pool.total_change(3), None);
+
+        assert!(mutator_pool.set_active(0, false));
+        assert!(!mutator_pool.set_active(3, false));
+        assert!(mutator_pool.create_all_events(0, 30).iter().all(|event| event.mutator_idx != 0));
+        assert!(mutator_pool.set_active(0, true));
+        assert!(mutator_pool.create_all_events(0, 30).iter().any(|event| event.mutator_idx == 0));
+
+        assert_eq!(mutator_pool.mutators_for(0), vec![1]);
+        assert_eq!(mutator_pool.mutators_for(1), vec![0, 2]);
+        assert!(mutator_pool.mutators_for(2).is_empty());
+    }
+
+    #[test]
+    fn pool_defaults() {
+        #[derive(Default)]
+        struct Pools {
+            assets: AssetPool,
+            mutators: MutatorPool
+        }
+
+        let pools = Pools::default();
+        assert!(pools.assets.is_empty() && pools.mutators.is_empty());
+        assert_eq!(pools.assets.policy(), MutationPolicy::Allow);
+
+        pools.assets.load(Asset::new(Decimal::new(100, 0)));
+        pools.mutators.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+
+        let modeller = Modeller::new(pools.assets.into_rc(), pools.mutators.into_rc());
+        assert_eq!(modeller.project_final(0, 30), Ok(vec![Decimal::new(130, 0)]));
+    }
+
+    #[test]
+    fn mutator_pool_remove() {
+        let mutator_pool = MutatorPool::new();
+
+        for target_idx in 0..4 {
+            mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+                0, target_idx, Decimal::ONE, Decimal::ZERO, true, 10, 0
+            ))));
+        }
+
+        let (removed, remap) = mutator_pool.remove_and_remap(1).unwrap();
+        assert_eq!(removed.borrow_base().target_idx, 1);
+        assert_eq!(remap, vec![(2, 1), (3, 2)]);
+        assert_eq!(mutator_pool.mutators_for(3), vec![2]);
+
+        assert_eq!(mutator_pool.remove(2).map(|mutator| mutator.borrow_base().target_idx), Some(3));
+        assert!(mutator_pool.remove(2).is_none());
+        assert_eq!(mutator_pool.len(), 2);
+    }
+
+    #[test]
+    fn mutator_pool_create_all_events() {
+        let mutator_pool = MutatorPool::new();
+
+        for (target_idx, cycle) in [(0, 10), (1, 15), (0, 25)] {
+            mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+                0, target_idx, Decimal::ONE, Decimal::ZERO, true, cycle, 0
+            ))));
+        }
+
+        let mut events = mutator_pool.create_all_events(1, 30);
+        events.sort();
+
+        assert_eq!(events, vec![
+            Event::new(10, 0, 0),
+            Event::new(15, 1, 1),
+            Event::new(20, 0, 0),
+            Event::new(25, 2, 0),
+            Event::new(30, 0, 0),
+            Event::new(30, 1, 1)
+        ]);
+    }
+
+    #[test]
+    fn event_stream() {
+        let mutator_pool = MutatorPool::new();
+
+        for (target_idx, cycle, offset) in [(0, 10, 0), (1, 15, 3), (0, 25, 0), (1, 0, 0)] {
+            mutator_pool.load(Box::new(StandardMutator(MutatorBase::with_offset(
+                0, target_idx, Decimal::ONE, Decimal::ZERO, true, cycle, 0, offset
+            ))));
+        }
+
+        let mut expected = mutator_pool.create_all_events(5, 100);
+        expected.sort();
+
+        let streamed: Vec<Event> = EventStream::new(&mutator_pool, 5, 100).collect();
+        assert_eq!(streamed, expected);
+
+        assert_eq!(EventStream::new(&mutator_pool, 101, 105).next(), None);
+    }
+
+    #[test]
+    fn event_stream_custom_schedules() {
+        let base = |target_idx, cycle| MutatorBase::new(0, target_idx, Decimal::ONE, Decimal::ZERO, true, cycle, 0);
+        let mutator_pool = MutatorPool::new();
+
+        mutator_pool.load(Box::new(StandardMutator(base(0, 10))));
+        mutator_pool.load(Box::new(PercentageMutator::new(base(1, 15))));
+        mutator_pool.load(Box::new(OneShotMutator::new(base(0, 1), 37)));
+        mutator_pool.load(Box::new(FixedScheduleMutator::new(base(1, 1), vec![90, 12, 12, 50])));
+        mutator_pool.load(Box::new(BackwardMutator(MutatorBase::with_offset(0, 0, Decimal::ONE, Decimal::ZERO, true, 30, 0, 95))));
+        mutator_pool.load(Box::new(WithdrawalMutator::new(base(1, 20))));
+        mutator_pool.load(Box::new(TransferMutator::new(base(1, 45), 0)));
+        mutator_pool.load(Box::new(ScaledMutator::new(base(0, 33))));
+        mutator_pool.load(Box::new(CompositeMutator::new(vec![
+            Box::new(OneShotMutator::new(base(1, 1), 64)), 
+            Box::new(StandardMutator(base(1, 10)))
+        ]).unwrap()));
+        mutator_pool.load(Box::new(StandardMutator(base(0, 0))));
+
+        let inactive = mutator_pool.load(Box::new(StandardMutator(base(0, 5))));
+        mutator_pool.set_active(inactive.raw(), false);
+
+        for (start, end) in [(0, 100), (5, 64), (38, 38), (96, 200)] {
+            let mut expected = mutator_pool.create_all_events(start, end);
+            expected.sort();
+
+            let streamed: Vec<Event> = EventStream::new(&mutator_pool, start, end).collect();
+            assert_eq!(streamed, expected, "window [{}, {}]", start, end);
+
+            let counted: u64 = (0..mutator_pool.len())
+                .filter(|idx| *idx != inactive.raw())
+                .map(|idx| mutator_pool.mutators.borrow()[idx].event_count(start, end))
+                .sum();
+            assert_eq!(counted as usize, expected.len(), "window [{}, {}]", start, end);
+        }
+    }
+
+    #[test]
+    fn modeller_with_events() {
+        let modeller = Modeller::with_events(AssetPool::new(), MutatorPool::new(), vec![
+            Event::new(20, 0, 0),
+            Event::new(10, 1, 0),
+            Event::new(20, 0, 1)
+        ]);
+
+        assert_eq!(modeller.events, vec![Event::new(10, 1, 0), Event::new(20, 0, 0), Event::new(20, 0, 1)]);
+    }
+
+    #[test]
+    fn modeller_events() {
+        let mut modeller = Modeller::new(AssetPool::new(), MutatorPool::new());
+
+        modeller.add_event(Event::new(20, 0, 0));
+        modeller.add_event(Event::new(10, 1, 0));
+        modeller.add_event(Event::new(20, 2, 0));
+        modeller.add_event(Event::new(15, 3, 0));
+
+        let order: Vec<usize> = modeller.events.iter().map(|event| event.mutator_idx).collect();
+        assert_eq!(order, vec![1, 3, 0, 2]);
+
+        modeller.add_event(Event::new(20, 1, 0));
+        let order: Vec<usize> = modeller.events.iter().map(|event| event.mutator_idx).collect();
+        assert_eq!(order, vec![1, 3, 0, 1, 2]);
+        modeller.remove_event(20, 1);
+
+        assert!(modeller.remove_event(20, 2).is_some());
+        assert!(modeller.remove_event(20, 1).is_none());
+        assert_eq!(modeller.events.len(), 3);
+
+        modeller.clear_events();
+        assert!(modeller.events.is_empty());
+    }
+
+    #[test]
+    fn modeller_time_unit() {
+        let mut modeller = Modeller::new(AssetPool::new(), MutatorPool::new());
+
+        assert_eq!(modeller.to_seconds(90), 90);
+
+        modeller.time_unit = 3600;
+        assert_eq!(modeller.to_seconds(12), 43200);
+        assert_eq!(modeller.from_seconds(43200), 12);
+        assert_eq!(modeller.from_seconds(43199), 11);
+        assert_eq!(modeller.to_seconds(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn modeller_time_unit_projection() {
+        let asset_pool = AssetPool::from_values([Decimal::new(100, 0)]);
+        let mutator_pool = MutatorPool::new();
+        let mut modeller = Modeller::new(asset_pool, mutator_pool.clone());
+        modeller.time_unit = 3600;
+
+        // A daily mutator over a week, counted in hours.
+        let day = modeller.from_seconds(24 * 60 * 60);
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::TEN, Decimal::ZERO, true, day as u32, 0
+        ))));
+
+        let week = modeller.from_seconds(7 * 24 * 60 * 60);
+        let result = modeller.project(0, week, 1, 0, None).unwrap();
+
+        assert_eq!(day, 24);
+        assert_eq!(result.final_values(), vec![Decimal::new(170, 0)]);
+        assert_eq!(result.interval_points()[0].time_pos(), week - 1);
+        assert_eq!(modeller.to_seconds(result.interval_points()[0].time_pos() + 1), 7 * 24 * 60 * 60);
+    }
+
+    #[test]
+    fn modeller_history() {
+        let asset_pool = AssetPool::from_values([Decimal::ONE]);
+        let mut modeller = Modeller::new(asset_pool.clone(), MutatorPool::new());
+
+        modeller.checkpoint();
+        assert!(!modeller.rewind());
+
+        modeller.history_capacity = 2;
+        for value in 1..=3 {
+            asset_pool.apply(AssetId(0), |_| Decimal::from(value));
+            modeller.checkpoint();
+        }
+        assert_eq!(modeller.history().len(), 2);
+
+        asset_pool.apply(AssetId(0), |_| Decimal::TEN);
+
+        assert!(modeller.rewind());
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(3, 0)));
+        assert!(modeller.rewind());
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(2, 0)));
+        assert!(!modeller.rewind());
+    }
+
+    #[test]
+    fn modeller_rewind_bypasses_policy() {
+        let asset_pool = AssetPool::with_policy(MutationPolicy::ClampAtZero);
+        asset_pool.load(Asset::new(Decimal::new(-100, 0)));
+
+        let mut modeller = Modeller::new(asset_pool.clone(), MutatorPool::new());
+        modeller.history_capacity = 1;
+        modeller.checkpoint();
+
+        asset_pool.mutate_raw(0, Decimal::new(150, 0));
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(50, 0)));
+
+        assert!(modeller.rewind());
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(-100, 0)));
+    }
+
+    #[test]
+    fn projection_interval_delay() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+
+        let modeller = Modeller::new(asset_pool, mutator_pool);
+
+        let times: Vec<u64> = modeller.preview(0, 20, 2, 15)
+            .iter()
+            .map(|event| event.time_pos)
+            .collect();
+        assert_eq!(times, vec![0, 10, 40, 50]);
+
+        let result = modeller.project(0, 20, 2, 15, None).unwrap();
+        assert_eq!(result.interval_points[1].time_pos, 54);
+        assert_eq!(result.final_values(), vec![Decimal::new(140, 0)]);
+    }
+
+    #[test]
+    fn projection_tie_break() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(SetMutator(MutatorBase::new(
+            0, 0, Decimal::ZERO, Decimal::ZERO, true, 0, 0
+        ))));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            1, 0, Decimal::new(10, 0), Decimal::ZERO, true, 0, 0
+        ))));
+
+        let mut modeller = Modeller::new(asset_pool, mutator_pool);
+        modeller.events.push(Event::new(5, 1, 0));
+        modeller.events.push(Event::new(5, 0, 0));
+
+        for _ in 0..3 {
+            let result = modeller.project(0, 10, 1, 0, None).unwrap();
+            assert_eq!(result.final_values(), vec![Decimal::new(10, 0)]);
+        }
+    }
+
+    #[test]
+    fn projection_preview() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            1, 0, Decimal::new(10, 0), Decimal::ZERO, true, 25, 5
+        ))));
+
+        let modeller = Modeller::new(asset_pool.clone(), mutator_pool);
+        let times: Vec<u64> = modeller.preview(0, 30, 2, 0)
+            .iter()
+            .map(|event| event.time_pos)
+            .collect();
+
+        assert_eq!(times, vec![0, 5, 10, 20, 30, 30, 40, 50, 55]);
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(100, 0)));
+    }
+
+    #[test]
+    fn projection_observer() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            1, 0, Decimal::new(-5, 0), Decimal::ZERO, true, 25, 5
+        ))));
+
+        let modeller = Modeller::new(asset_pool, mutator_pool);
+        let mut ledger = Vec::new();
+        let result = modeller.project_with_observer(0, 30, 1, 0, None, |log| ledger.push(log)).unwrap();
+
+        let entries: Vec<(u64, usize, Decimal, Decimal)> = ledger
+            .iter()
+            .map(|log| (log.time_pos(), log.mutator_idx(), log.before(), log.after()))
+            .collect();
+
+        assert_eq!(entries, vec![
+            (0, 0, Decimal::new(100, 0), Decimal::new(110, 0)), 
+            (5, 1, Decimal::new(110, 0), Decimal::new(105, 0)), 
+            (10, 0, Decimal::new(105, 0), Decimal::new(115, 0)), 
+            (20, 0, Decimal::new(115, 0), Decimal::new(125, 0))
+        ]);
+        assert!(ledger.iter().all(|log| log.asset_idx() == 0));
+        assert_eq!(result.metrics().events_triggered(), ledger.len());
+    }
+
+    #[test]
+    fn projection_solve_initial() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(1000, 0)));
+        mutator_pool.load(Box::new(CompoundMutator::new(
+            MutatorBase::new(0, 0, Decimal::new(1, 2), Decimal::ZERO, true, 10, 0),
+            Decimal::new(50, 0)
+        )));
+
+        let modeller = Modeller::new(asset_pool.clone(), mutator_pool);
+        let tolerance = Decimal::new(1, 6);
+
+        // The principal that twelve payments of 50 at 1% per period pay off.
+        let principal = modeller.solve_initial(0, Decimal::ZERO, 0, 120, tolerance).unwrap();
+        assert_eq!(principal.round_dp(4), Decimal::new(5627539, 4));
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(1000, 0)));
+
+        asset_pool.replace(AssetId(0), principal);
+        assert!(modeller.project_final(0, 120).unwrap()[0].abs() <= tolerance);
+
+        assert_eq!(modeller.solve_initial(1, Decimal::ZERO, 0, 120, tolerance), None);
+    }
+
+    #[test]
+    fn projection_extrema() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        asset_pool.load(Asset::new(Decimal::new(7, 0)));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(-150, 0), Decimal::ZERO, true, 30, 10
+        ))));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            1, 0, Decimal::new(200, 0), Decimal::ZERO, true, 30, 20
+        ))));
+
+        let modeller = Modeller::new(asset_pool, mutator_pool);
+        let (result, extrema) = modeller.project_with_extrema(0, 30, 2, 0, None).unwrap();
+
+        // The asset dips to -50 mid-interval, but recovers before each end.
+        assert_eq!(result.final_values(), vec![Decimal::new(200, 0), Decimal::new(7, 0)]);
+        assert_eq!(extrema, vec![
+            (Decimal::new(-50, 0), Decimal::new(200, 0)), 
+            (Decimal::new(7, 0), Decimal::new(7, 0))
+        ]);
+    }
+
+    #[test]
+    fn projection_extrema_transfer_source() {
+        let asset_pool = AssetPool::from_values([Decimal::new(1000, 0), Decimal::ZERO]);
+        let mutator_pool = MutatorPool::new();
+
+        mutator_pool.load(Box::new(TransferMutator::new(
+            MutatorBase::new(0, 1, Decimal::new(5, 1), Decimal::ZERO, true, 10, 0),
+            0
+        )));
+
+        let modeller = Modeller::new(asset_pool, mutator_pool);
+        let (result, extrema) = modeller.project_with_extrema(0, 30, 1, 0, None).unwrap();
+
+        assert_eq!(result.final_values(), vec![Decimal::new(125, 0), Decimal::new(875, 0)]);
+        assert_eq!(extrema, vec![
+            (Decimal::new(125, 0), Decimal::new(1000, 0)), 
+            (Decimal::ZERO, Decimal::new(875, 0))
+        ]);
+    }
+
+    #[test]
+    fn projection_final() {
+        let build = || {
+            let asset_pool = AssetPool::new();
+            let mutator_pool = MutatorPool::new();
+
+            asset_pool.load(Asset::new(Decimal::new(100, 0)));
+            mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+                0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+            ))));
+
+            Modeller::new(asset_pool, mutator_pool)
+        };
+
+        let expected = build().project(0, 30, 4, 0, None).unwrap().final_values();
+
+        assert_eq!(build().project_final(0, 120), Ok(expected));
+        assert_eq!(
+            build().project_final(0, 0), 
+            Err(ProjectionError::InvalidInterval { interval_len: 0 })
+        );
+    }
+
+    /// Compares `project_final` against `project` capturing every step.
+    /// Run with `cargo test --release -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn projection_final_timing() {
+        const ASSETS: usize = 100;
+        const STEPS: u32 = 5_000;
+
+        let build = || {
+            let asset_pool = AssetPool::with_capacity(ASSETS);
+            let mutator_pool = MutatorPool::new();
+
+            for idx in 0..ASSETS {
+                asset_pool.load(Asset::new(Decimal::new(100, 0)));
+                mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+                    idx, 0, Decimal::ONE, Decimal::ZERO, true, 1, 0
+                ))));
+            }
+
+            Modeller::new(asset_pool, mutator_pool)
+        };
+
+        let modeller = build();
+        let started = std::time::Instant::now();
+        let expected = modeller.project(0, 1, STEPS, 0, None).unwrap().final_values();
+        let project_time = started.elapsed();
+
+        let modeller = build();
+        let started = std::time::Instant::now();
+        let values = modeller.project_final(0, STEPS as u64).unwrap();
+        let final_time = started.elapsed();
+
+        assert_eq!(values, expected);
+        println!(
+            "{} assets over {} steps: project {:?}, project_final {:?}", 
+            ASSETS, STEPS, project_time, final_time
+        );
+    }
+
+    #[test]
+    fn projection_periods() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+
+        let modeller = Modeller::new(asset_pool.clone(), mutator_pool);
+        let result = modeller.project_periods(&[(0, 30), (30, 35), (50, 91)], None).unwrap();
+
+        let values: Vec<(u64, Decimal)> = result.interval_points
+            .iter()
+            .map(|point| (point.time_pos, point.asset_captures[0].value))
+            .collect();
+        assert_eq!(values, vec![
+            (29, Decimal::new(130, 0)),
+            (34, Decimal::new(140, 0)),
+            (90, Decimal::new(190, 0))
+        ]);
+
+        assert_eq!(
+            modeller.project_periods(&[(0, 30), (40, 40)], None).err(), 
+            Some(ProjectionError::InvalidInterval { interval_len: 0 })
+        );
+    }
+
+    #[test]
+    fn projection_event_limit() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 1, 0
+        ))));
+
+        let mut modeller = Modeller::new(asset_pool.clone(), mutator_pool);
+        modeller.max_events = Some(50);
+
+        assert_eq!(
+            modeller.project(0, 30, 2, 0, None).err(), 
+            Some(ProjectionError::EventLimitExceeded { generated: 60, limit: 50 })
+        );
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(100, 0)));
+
+        assert!(modeller.project(0, 25, 2, 0, None).is_ok());
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(600, 0)));
+    }
+
+    #[test]
+    fn projection_event_limit_custom_schedules() {
+        let limited = |mutator: Box<dyn Mutator>| {
+            let mutator_pool = MutatorPool::new();
+            mutator_pool.load(mutator);
+
+            let mut modeller = Modeller::new(AssetPool::from_values([Decimal::ZERO]), mutator_pool);
+            modeller.max_events = Some(10);
+            modeller.project(0, 1000, 1, 0, None).err()
+        };
+        let base = |cycle| MutatorBase::new(0, 0, Decimal::ONE, Decimal::ZERO, true, cycle, 0);
+
+        assert_eq!(limited(Box::new(OneShotMutator::new(base(1), 500))), None);
+        assert_eq!(
+            limited(Box::new(FixedScheduleMutator::new(base(0), (0..1000).collect()))), 
+            Some(ProjectionError::EventLimitExceeded { generated: 1000, limit: 10 })
+        );
+        assert_eq!(
+            limited(Box::new(BackwardMutator(MutatorBase::with_offset(0, 0, Decimal::ONE, Decimal::ZERO, true, 10, 0, 200)))), 
+            Some(ProjectionError::EventLimitExceeded { generated: 21, limit: 10 })
+        );
+        assert_eq!(limited(Box::new(BackwardMutator(base(100)))), None);
+    }
+
+    #[test]
+    fn projection_ctx_events() {
+        /// Repays `change` until the balance falls below `change`.
+        struct Payoff(MutatorBase);
+
+        impl Mutator for Payoff {
+            fn on_event(&self, original_value: Decimal) -> Decimal {
+                original_value - self.0.change
+            }
+
+            fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+                self.0.create_events(start, end, idx)
+            }
+
+            fn create_events_ctx(&self, start: u64, end: u64, idx: usize, asset_pool: &AssetPool) -> Vec<Event> {
+                match asset_pool.get_raw(self.0.target_idx) {
+                    Some(balance) if balance >= self.0.change => self.create_events(start, end, idx),
+                    _ => Vec::new()
+                }
+            }
+
+            fn capture(&self) -> crate::MutatorCapture {
+                crate::MutatorCapture::new(&self.0, MutatorVariant::Custom("payoff".to_string()))
+            }
+
+            fn reset(&mut self, capture: crate::MutatorCapture) {
+                self.0.total_change.set(capture.base().total_change());
+            }
+
+            fn borrow_base(&self) -> &MutatorBase {
+                &self.0
+            }
+        }
+
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(50, 0)));
+        mutator_pool.load(Box::new(Payoff(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+
+        let modeller = Modeller::new(asset_pool.clone(), mutator_pool);
+        let result = modeller.project(0, 30, 4, 0, None).unwrap();
+
+        assert_eq!(result.interval_points[1].asset_captures[0].value, Decimal::new(-10, 0));
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(-10, 0)));
+    }
+
+    #[test]
+    fn projection_stop_when_stable() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(RangeMutator::new(
+            MutatorBase::new(0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0),
+            None,
+            Some(Decimal::new(150, 0))
+        )));
+
+        let mut modeller = Modeller::new(asset_pool, mutator_pool);
+        modeller.stop_when_stable = Some(Decimal::new(1, 2));
+
+        let result = modeller.project(0, 30, 10, 0, None).unwrap();
+
+        assert_eq!(result.interval_points.len(), 3);
+        assert_eq!(result.final_values(), vec![Decimal::new(150, 0)]);
+    }
+
+    #[test]
+    fn projection_errors() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+        let modeller = Modeller::new(asset_pool.clone(), mutator_pool.clone());
+
+        assert_eq!(modeller.project(0, 30, 1, 0, None).err(), Some(ProjectionError::NoAssets));
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        assert_eq!(modeller.project(0, 30, 1, 0, None).err(), Some(ProjectionError::NoMutators));
+
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+        assert_eq!(
+            modeller.project(0, 0, 1, 0, None).err(), 
+            Some(ProjectionError::InvalidInterval { interval_len: 0 })
+        );
+        assert!(modeller.project(0, 30, 1, 0, None).is_ok());
+
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 3, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+        assert_eq!(modeller.validate(), Err(vec![1]));
+        assert_eq!(
+            modeller.project(0, 30, 1, 0, None).err(), 
+            Some(ProjectionError::InvalidTargets(vec![1]))
+        );
+    }
+
+    #[test]
+    fn event_formatting() {
+        let event = Event::new(30, 1, 2);
+
+        assert_eq!(event.to_string(), "Event@30 mut#1 -> asset#2");
+        assert_eq!(format!("{:?}", event), "Event { time_pos: 30, mutator_idx: 1, asset_idx: 2, applied: false }");
+        assert_eq!(format!("{:?}", Asset::new(Decimal::new(5090, 2))), "Asset(50.90)");
+    }
+
+    #[test]
+    fn event_shifted() {
+        let event = Event::new(30, 1, 2);
+
+        assert_eq!(event.shifted(15), Event::new(45, 1, 2));
+        assert_eq!(event.shifted(u64::MAX).time_pos, u64::MAX);
+        assert_eq!(event.clone(), event);
+    }
+
+    #[test]
+    fn event_spread() {
+        let times = |n| -> Vec<u64> {
+            crate::spread_events(0, 360, n, 0, 0).iter().map(|event| event.time_pos).collect()
+        };
+
+        assert!(times(0).is_empty());
+        assert_eq!(times(1), vec![0]);
+        assert_eq!(times(2), vec![0, 360]);
+        assert_eq!(times(5), vec![0, 90, 180, 270, 360]);
+        assert_eq!(times(4), vec![0, 120, 240, 360]);
+
+        assert_eq!(crate::spread_events(10, 10, 3, 1, 2), vec![Event::new(10, 1, 2); 3]);
+        assert!(crate::spread_events(10, 5, 3, 1, 2).is_empty());
+    }
+
+    #[test]
+    fn event_trigger_errors() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(100, 0)));
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ))));
+
+        assert_eq!(Event::new(0, 0, 1).trigger(&asset_pool, &mutator_pool), Err(TriggerError::AssetNotFound(1)));
+        assert_eq!(Event::new(0, 1, 0).trigger(&asset_pool, &mutator_pool), Err(TriggerError::MutatorNotFound(1)));
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(100, 0)));
+
+        assert_eq!(Event::new_checked(0, 0, 1, &asset_pool, &mutator_pool), Err(crate::EventError::AssetNotFound(1)));
+        assert_eq!(Event::new_checked(0, 1, 0, &asset_pool, &mutator_pool), Err(crate::EventError::MutatorNotFound(1)));
+        assert_eq!(Event::new_checked(0, 0, 0, &asset_pool, &mutator_pool), Ok(Event::new(0, 0, 0)));
+
+        assert_eq!(Event::new(0, 0, 0).trigger(&asset_pool, &mutator_pool), Ok(()));
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(110, 0)));
+
+        let event = Event::new(0, 0, 0);
+        event.trigger(&asset_pool, &mutator_pool).unwrap();
+        event.trigger(&asset_pool, &mutator_pool).unwrap();
+        assert!(event.is_applied());
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(120, 0)));
+
+        event.reset_applied();
+        event.trigger(&asset_pool, &mutator_pool).unwrap();
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(130, 0)));
+        assert_eq!(event, Event::new(0, 0, 0));
+
+        let mut modeller = Modeller::new(asset_pool, mutator_pool);
+        modeller.add_event(Event::new(5, 1, 0));
+
+        assert_eq!(
+            modeller.project(0, 30, 1, 0, None).err(), 
+            Some(ProjectionError::Trigger(TriggerError::MutatorNotFound(1)))
+        );
+    }
+
+    #[test]
+    fn modeller_applied_events() {
+        let asset_pool = AssetPool::from_values([Decimal::new(100, 0)]);
+        let mutator_pool = MutatorPool::new();
+
+        mutator_pool.load(Box::new(StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, false, 0, 0
+        ))));
+
+        let event = Event::new(5, 0, 0);
+        event.trigger(&asset_pool, &mutator_pool).unwrap();
+        assert!(event.is_applied());
+
+        let mut modeller = Modeller::new(asset_pool.clone(), mutator_pool);
+        modeller.add_event(event);
+
+        modeller.project(0, 30, 1, 0, None).unwrap();
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(120, 0)));
+
+        modeller.project(0, 30, 1, 0, None).unwrap();
+        assert_eq!(asset_pool.get_raw(0), Some(Decimal::new(130, 0)));
+        assert!(modeller.events[0].is_applied());
+    }
+
+    #[test]
+    fn compound_mutator() {
+        let mutator = CompoundMutator::new(
+            MutatorBase::new(0, 0, Decimal::new(1, 2), Decimal::ZERO, true, 30, 0),
+            Decimal::new(50, 0)
+        );
+
+        assert_eq!(mutator.on_event(Decimal::new(1000, 0)), Decimal::new(960, 0));
+        assert_eq!(mutator.capture().variant(), &MutatorVariant::Compound);
+    }
+
+    #[test]
+    fn per_period_rounding() {
+        let base = || MutatorBase::new(0, 0, Decimal::new(4, 3), Decimal::ZERO, true, 30, 0);
+        let rounded = PercentageMutator::with_rounding(base(), Some(2));
+        let unrounded = PercentageMutator::new(base());
+        let compound = CompoundMutator::with_rounding(base(), Decimal::ZERO, Some(2));
+
+        let (mut a, mut b, mut c) = (Decimal::new(1000, 0), Decimal::new(1000, 0), Decimal::new(1000, 0));
+
+        for _ in 0..360 {
+            a = rounded.on_event(a);
+            b = unrounded.on_event(b);
+            c = compound.on_event(c);
+        }
+
+        assert_eq!(a, Decimal::new(420849, 2));
+        assert_eq!(b.round_dp(2), Decimal::new(420859, 2));
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn backward_mutator() {
+        let mutator = BackwardMutator(MutatorBase::new(
+            0, 0, Decimal::ONE, Decimal::ZERO, true, 30, 100
+        ));
+
+        let times: Vec<u64> = mutator.create_events(10, 100, 0)
+            .iter()
+            .map(|event| event.time_pos)
+            .collect();
+
+        assert_eq!(times, vec![10, 40, 70, 100]);
+
+        let times: Vec<u64> = mutator.create_events(15, 100, 0)
+            .iter()
+            .map(|event| event.time_pos)
+            .collect();
+
+        assert_eq!(times, vec![40, 70, 100]);
+
+        let times: Vec<u64> = mutator.create_events(0, 200, 0)
+            .iter()
+            .map(|event| event.time_pos)
+            .collect();
+
+        assert_eq!(times, vec![10, 40, 70, 100]);
+        assert!(mutator.create_events(101, 200, 0).is_empty());
+        assert!(mutator.create_events(41, 69, 0).is_empty());
+    }
+
+    #[test]
+    fn backward_mutator_projection() {
+        let asset_pool = AssetPool::from_values([Decimal::ZERO]);
+        let mutator_pool = MutatorPool::new();
+
+        mutator_pool.load(Box::new(BackwardMutator(MutatorBase::with_offset(
+            0, 0, Decimal::ONE, Decimal::ZERO, true, 30, 80, 9
+        ))));
+
+        let modeller = Modeller::new(asset_pool, mutator_pool);
+        let mut times = Vec::new();
+        let result = modeller.project_with_observer(0, 45, 3, 0, None, |log| times.push(log.time_pos())).unwrap();
+
+        // The spacing holds across the interval boundary at 45.
+        assert_eq!(times, vec![29, 59, 89]);
+        assert_eq!(result.final_values(), vec![Decimal::new(3, 0)]);
+    }
+
+    #[test]
+    fn conditional_mutator() {
+        let mutator = ConditionalMutator::new(
+            MutatorBase::new(0, 0, Decimal::new(-500, 0), Decimal::ZERO, true, 30, 0),
+            |value| value > Decimal::new(1000, 0)
+        );
+
+        assert_eq!(mutator.on_event(Decimal::new(1200, 0)), Decimal::new(700, 0));
+        assert_eq!(mutator.on_event(Decimal::new(900, 0)), Decimal::new(900, 0));
+        assert_eq!(mutator.capture().variant(), &MutatorVariant::Conditional);
+    }
+
+    #[test]
+    fn transfer_mutator() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        let source = asset_pool.load(Asset::new(Decimal::new(1000, 0)));
+        let target = asset_pool.load(Asset::new(Decimal::new(50, 0)));
+        let mutator = mutator_pool.load(Box::new(TransferMutator::new(
+            MutatorBase::new(0, target.raw(), Decimal::new(1, 1), Decimal::ZERO, true, 30, 0),
+            source.raw()
+        )));
+
+        Event::new(0, mutator.raw(), target.raw()).trigger(&asset_pool, &mutator_pool).unwrap();
+
+        assert_eq!(asset_pool.get(source), Some(Decimal::new(900, 0)));
+        assert_eq!(asset_pool.get(target), Some(Decimal::new(150, 0)));
+    }
+
+    #[test]
+    fn transfer_mutator_policy() {
+        let run = |policy, rate, target_value: i64| {
+            let asset_pool = AssetPool::with_policy(policy);
+            let mutator_pool = MutatorPool::new();
+
+            asset_pool.load(Asset::new(Decimal::new(100, 0)));
+            asset_pool.load(Asset::new(Decimal::ZERO));
+            asset_pool.replace(AssetId(1), Decimal::from(target_value));
+            mutator_pool.load(Box::new(TransferMutator::new(
+                MutatorBase::new(0, 1, rate, Decimal::ZERO, true, 30, 0),
+                0
+            )));
+
+            let triggered = Event::new(0, 0, 1).trigger(&asset_pool, &mutator_pool);
+            (triggered.is_ok(), asset_pool.read_snapshot())
+        };
+
+        // Only what the clamped source actually loses is credited.
+        assert_eq!(
+            run(MutationPolicy::ClampAtZero, Decimal::new(15, 1), 0), 
+            (true, vec![Decimal::ZERO, Decimal::new(100, 0)])
+        );
+        assert_eq!(
+            run(MutationPolicy::Allow, Decimal::new(15, 1), 0), 
+            (true, vec![Decimal::new(-50, 0), Decimal::new(150, 0)])
+        );
+
+        // A rejected debit or credit transfers nothing.
+        assert_eq!(
+            run(MutationPolicy::ErrorOnNegative, Decimal::new(15, 1), 0), 
+            (true, vec![Decimal::new(100, 0), Decimal::ZERO])
+        );
+        assert_eq!(
+            run(MutationPolicy::ErrorOnNegative, Decimal::new(5, 1), -500), 
+            (false, vec![Decimal::new(100, 0), Decimal::new(-500, 0)])
+        );
+    }
+
+    #[test]
+    fn transfer_mutator_limits() {
+        let asset_pool = AssetPool::from_values([Decimal::new(-900, 0), Decimal::new(900, 0)]);
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.set_limits(MutationLimits::new(None, Some(Decimal::new(1000, 0))));
+        let mutator = mutator_pool.load(Box::new(TransferMutator::new(
+            MutatorBase::new(0, 0, Decimal::TWO, Decimal::ZERO, true, 30, 0),
+            1
+        )));
+
+        // Each leg moves 1800, so neither may be applied.
+        let triggered = Event::new(0, mutator.raw(), 0).trigger(&asset_pool, &mutator_pool);
+
+        assert!(triggered.is_ok());
+        assert_eq!(asset_pool.read_snapshot(), vec![Decimal::new(-900, 0), Decimal::new(900, 0)]);
+        assert_eq!(mutator_pool.total_change(mutator.raw()), Some(Decimal::ZERO));
+    }
+
+    #[test]
+    fn mutators_record_total_change() {
+        let base = || MutatorBase::new(0, 1, Decimal::new(5, 2), Decimal::ZERO, true, 10, 0);
+
+        #[allow(unused_mut)]
+        let mut mutators: Vec<Box<dyn Mutator>> = vec![
+            Box::new(StandardMutator(base())), 
+            Box::new(PercentageMutator::with_rounding(base(), Some(2))), 
+            Box::new(CompoundMutator::new(base(), Decimal::ONE)), 
+            Box::new(BackwardMutator(base())), 
+            Box::new(ConditionalMutator::new(base(), |value| value < Decimal::new(10005, 2))), 
+            Box::new(TransferMutator::new(base(), 0)), 
+            Box::new(ScaledMutator::new(base())), 
+            Box::new(TieredMutator::new(base(), vec![(Decimal::ZERO, Decimal::ONE)])), 
+            Box::new(CappedMutator::new(base(), Decimal::new(8, 2))), 
+            Box::new(RangeMutator::new(base(), None, Some(Decimal::new(10008, 2)))), 
+            Box::new(StepMutator::new(base(), Decimal::TEN, 2)), 
+            Box::new(SetMutator(base())), 
+            Box::new(WithdrawalMutator::new(base())), 
+            Box::new(OneShotMutator::new(base(), 0)), 
+            Box::new(FixedScheduleMutator::new(base(), vec![0]))
+        ];
+
+        #[cfg(feature = "rand")]
+        mutators.push(Box::new(crate::RandomWalkMutator::new(base(), 0.01, 0.02, 7)));
+
+        for (i, mutator) in mutators.iter().enumerate() {
+            let asset_pool = AssetPool::from_values([Decimal::new(1000, 0)]);
+            let initial = Decimal::ONE_HUNDRED;
+            let last = (0..3).fold(initial, |value, _| mutator.on_event_ctx(value, &asset_pool));
+
+            assert_eq!(mutator.borrow_base().total_change.get(), last - initial, "mutator {}", i);
+        }
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn capture_roundtrip() {
+        let base = || MutatorBase::new(0, 0, Decimal::new(5, 2), Decimal::new(25, 0), true, 10, 0);
+
+        crate::testing::assert_capture_roundtrip(&mut StandardMutator(base()));
+        crate::testing::assert_capture_roundtrip(&mut PercentageMutator::new(base()));
+        crate::testing::assert_capture_roundtrip(&mut CompoundMutator::new(base(), Decimal::ONE));
+        crate::testing::assert_capture_roundtrip(&mut BackwardMutator(base()));
+        crate::testing::assert_capture_roundtrip(&mut SetMutator(base()));
+    }
+
+    #[test]
+    fn scaled_mutator() {
+        let mut mutator = ScaledMutator::new(MutatorBase::new(
+            0, 0, Decimal::new(10, 0), Decimal::ZERO, true, 10, 0
+        ));
+
+        let value = mutator.on_event(Decimal::ZERO);
+        let capture = mutator.capture();
+        assert_eq!(capture.data(), "1");
+
+        let value = mutator.on_event(value);
+        assert_eq!(mutator.on_event(value), Decimal::new(60, 0));
+
+        mutator.reset(capture);
+        assert_eq!(mutator.count(), 1);
+        assert_eq!(mutator.on_event(Decimal::ZERO), Decimal::new(20, 0));
+    }
+
+    #[test]
+    fn range_mutator() {
+        let base = |change| MutatorBase::new(0, 0, change, Decimal::ZERO, true, 10, 0);
+
+        let capped = RangeMutator::new(base(Decimal::new(500, 0)), None, Some(Decimal::new(1000, 0)));
+        assert_eq!(capped.on_event(Decimal::new(300, 0)), Decimal::new(800, 0));
+        assert_eq!(capped.on_event(Decimal::new(800, 0)), Decimal::new(1000, 0));
+
+        let floored = RangeMutator::new(base(Decimal::new(-500, 0)), Some(Decimal::ZERO), None);
+        assert_eq!(floored.on_event(Decimal::new(300, 0)), Decimal::ZERO);
+
+        let unbounded = RangeMutator::new(base(Decimal::new(-500, 0)), None, None);
+        assert_eq!(unbounded.on_event(Decimal::new(300, 0)), Decimal::new(-200, 0));
+    }
+
+    #[test]
+    fn step_mutator() {
+        let mut mutator = StepMutator::new(
+            MutatorBase::new(0, 0, Decimal::new(100, 0), Decimal::ZERO, true, 30, 0),
+            Decimal::new(150, 0),
+            2
+        );
+
+        let value = mutator.on_event(Decimal::ZERO);
+        let capture = mutator.capture();
+        let value = mutator.on_event(value);
+        assert_eq!(mutator.on_event(value), Decimal::new(350, 0));
+        assert_eq!(mutator.count(), 3);
+
+        mutator.reset(capture);
+        assert_eq!(mutator.on_event(Decimal::ZERO), Decimal::new(100, 0));
+        assert_eq!(mutator.on_event(Decimal::ZERO), Decimal::new(150, 0));
+    }
+
+    #[test]
+    fn set_mutator() {
+        let mutator = SetMutator(MutatorBase::new(0, 0, Decimal::ZERO, Decimal::ZERO, true, 30, 0));
+
+        assert_eq!(mutator.on_event(Decimal::new(1250, 0)), Decimal::ZERO);
+        assert_eq!(mutator.capture().variant(), &MutatorVariant::Set);
+    }
+
+    #[test]
+    fn withdrawal_mutator() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(95, 0)));
+        mutator_pool.load(Box::new(WithdrawalMutator::new(MutatorBase::new(
+            0, 0, Decimal::new(20, 0), Decimal::ZERO, true, 10, 0
+        ))));
+
+        let mut modeller = Modeller::new(asset_pool.clone(), mutator_pool.clone());
+        modeller.record_every_event = true;
+        let result = modeller.project(0, 30, 4, 0, None).unwrap();
+
+        assert!(result.timeseries(0).iter().all(|(_, value)| *value >= Decimal::ZERO));
+
+        assert_eq!(result.interval_points[1].asset_captures[0].value, Decimal::ZERO);
+        assert_eq!(mutator_pool.total_change(0), Some(Decimal::new(-95, 0)));
+        assert_eq!(mutator_pool.capture()[0].data(), "true");
+        assert!(mutator_pool.create_all_events(120, 200).is_empty());
+    }
+
+    #[test]
+    fn one_shot_mutator() {
+        let mutator = OneShotMutator::new(
+            MutatorBase::new(0, 2, Decimal::new(10000, 0), Decimal::ZERO, true, 0, 0), 
+            50
+        );
+
+        assert_eq!(mutator.create_events(0, 100, 1), vec![Event::new(50, 1, 2)]);
+        assert_eq!(mutator.create_events(50, 50, 1), vec![Event::new(50, 1, 2)]);
+        assert!(mutator.create_events(0, 49, 1).is_empty());
+        assert!(mutator.create_events(51, 100, 1).is_empty());
+
+        assert_eq!(mutator.on_event(Decimal::new(500, 0)), Decimal::new(10500, 0));
+    }
+
+    #[test]
+    fn fixed_schedule_mutator() {
+        let mutator = FixedScheduleMutator::new(
+            MutatorBase::new(0, 1, Decimal::new(25, 0), Decimal::ZERO, true, 0, 0), 
+            vec![90, 12, 47, 150, 3]
+        );
+
+        assert_eq!(mutator.create_events(10, 100, 2), vec![
+            Event::new(12, 2, 1), 
+            Event::new(47, 2, 1), 
+            Event::new(90, 2, 1)
+        ]);
+        assert!(mutator.create_events(100, 149, 2).is_empty());
+
+        assert_eq!(mutator.on_event(Decimal::new(100, 0)), Decimal::new(125, 0));
+        assert_eq!(mutator.capture().base.total_change, Decimal::new(25, 0));
+    }
+
+    #[test]
+    fn tiered_mutator() {
+        let mutator = TieredMutator::new(
+            MutatorBase::new(0, 0, Decimal::ZERO, Decimal::ZERO, true, 30, 0), 
+            vec![
+                (Decimal::new(40000, 0), Decimal::new(4, 1)),
+                (Decimal::ZERO, Decimal::new(1, 1)),
+                (Decimal::new(10000, 0), Decimal::new(2, 1))
+            ]
+        );
+
+        assert_eq!(mutator.tiered_amount(Decimal::new(5000, 0)), Decimal::new(500, 0));
+        assert_eq!(mutator.tiered_amount(Decimal::new(50000, 0)), Decimal::new(11000, 0));
+        assert_eq!(mutator.on_event(Decimal::new(50000, 0)), Decimal::new(39000, 0));
+        assert_eq!(mutator.capture().base.total_change, Decimal::new(-11000, 0));
+    }
+
+    #[test]
+    fn capped_mutator() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::ZERO));
+        mutator_pool.load(Box::new(CappedMutator::new(
+            MutatorBase::new(0, 0, Decimal::new(300, 0), Decimal::ZERO, true, 10, 0),
+            Decimal::new(1000, 0)
+        )));
+
+        let modeller = Modeller::new(asset_pool, mutator_pool.clone());
+        let mut result = modeller.project(0, 20, 3, 0, None).unwrap();
+
+        let values: Vec<Decimal> = result.interval_points
+            .iter()
+            .map(|point| point.asset_captures[0].value)
+            .collect();
+        assert_eq!(values, vec![Decimal::new(600, 0), Decimal::new(1000, 0), Decimal::new(1000, 0)]);
+        assert_eq!(mutator_pool.total_change(0), Some(Decimal::new(1000, 0)));
+
+        let midpoint = result.event_mementos.remove(1);
+        assert_eq!(midpoint.time_pos, 20);
+
+        let resumed = modeller.project(0, 20, 3, 0, Some(midpoint)).unwrap();
+        assert_eq!(resumed.final_values(), vec![Decimal::new(1000, 0)]);
+    }
+
+    #[test]
+    fn accrual_mutator() {
+        let asset_pool = AssetPool::new();
+        let mutator_pool = MutatorPool::new();
+
+        asset_pool.load(Asset::new(Decimal::new(1000, 0)));
+        mutator_pool.load(Box::new(AccrualMutator::new(
+            MutatorBase::with_offset(0, 0, Decimal::new(1, 4), Decimal::ZERO, true, 1, 0, 1),
+            30
+        )));
+
+        let modeller = Modeller::new(asset_pool, mutator_pool);
+        let result = modeller.project(1, 29, 2, 0, None).unwrap();
+
+        let values: Vec<Decimal> = result.interval_points
+            .iter()
+            .map(|point| point.asset_captures[0].value)
+            .collect();
+
+        // Days 1 to 29 accrue 0.1 each on 1000, day 30 posts 3.0, then
+        // days 31 to 58 accrue 0.1003 each on the new balance of 1003.
+        assert_eq!(values[0], Decimal::new(1000, 0));
+        assert_eq!(result.interval_points[0].mutator_captures[0].base().total_change(), Decimal::new(29, 1));
+        assert_eq!(values[1], Decimal::new(1003, 0));
+        assert_eq!(result.interval_points[1].mutator_captures[0].base().total_change(), Decimal::new(28084, 4));
+    }
+
+    #[test]
+    fn composite_mutator() {
+        let mut mutator = CompositeMutator::new(vec![
+            Box::new(StandardMutator(MutatorBase::new(0, 0, Decimal::new(100, 0), Decimal::ZERO, true, 30, 0))),
+            Box::new(PercentageMutator::new(MutatorBase::new(0, 0, Decimal::new(1, 1), Decimal::ZERO, true, 10, 0))),
+            Box::new(StepMutator::new(
+                MutatorBase::new(0, 0, Decimal::ONE, Decimal::ZERO, true, 10, 0), Decimal::ZERO, 1
+            ))
+        ]).unwrap();
+
+        let capture = mutator.capture();
+        assert_eq!(capture.children().len(), 3);
+        assert_eq!(capture.children()[2].data(), "0");
+
+        assert_eq!(mutator.on_event(Decimal::new(900, 0)), Decimal::new(1101, 0));
+        assert_eq!(mutator.on_event(Decimal::new(900, 0)), Decimal::new(1100, 0));
+        assert_eq!(mutator.create_events(0, 59, 4).len(), 2);
+
+        mutator.reset(capture);
+        assert_eq!(mutator.on_event(Decimal::new(900, 0)), Decimal::new(1101, 0));
+
+        assert!(CompositeMutator::new(Vec::new()).is_none());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_walk_mutator() {
+        let run = |seed| {
+            let asset_pool = AssetPool::new();
+            let mutator_pool = MutatorPool::new();
+
+            asset_pool.load(Asset::new(Decimal::new(1000, 0)));
+            mutator_pool.load(Box::new(crate::RandomWalkMutator::new(
+                MutatorBase::new(0, 0, Decimal::ZERO, Decimal::ZERO, true, 10, 0), 0.01, 0.05, seed
+            )));
+
+            let modeller = Modeller::new(asset_pool, mutator_pool);
+            let mut result = modeller.project(0, 100, 4, 0, None).unwrap();
+            let full = result.final_values();
+
+            let resumed = modeller.project(0, 100, 4, 0, Some(result.event_mementos.remove(2))).unwrap();
+            assert_eq!(resumed.final_values(), full);
+
+            full
+        };
+
+        assert_eq!(run(7), run(7));
+        assert_ne!(run(7), run(8));
+    }
+
+    #[test]
+    fn standard_mutator_total_change() {
+        let mut mutator = StandardMutator(MutatorBase::new(
+            0, 0, Decimal::new(15, 1), Decimal::ZERO, true, 10, 0
+        ));
+
+        let initial = mutator.capture();
+        (0..3).fold(Decimal::ZERO, |value, _| mutator.on_event(value));
+        assert_eq!(mutator.capture().base.total_change, Decimal::new(45, 1));
+
+        mutator.reset(initial);
+        assert_eq!(mutator.0.total_change.get(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn percentage_mutator() {
+        let mut mutator = PercentageMutator::new(MutatorBase::new(
+            0, 0, Decimal::new(5, 2), Decimal::new(25, 0), true, 10, 0
+        ));
+
+        assert_eq!(mutator.on_event(Decimal::new(200, 0)), Decimal::new(210, 0));
+
+        let capture = mutator.capture();
+        assert_eq!(capture.variant(), &MutatorVariant::Percentage);
+
+        mutator.base.total_change.set(Decimal::ZERO);
+        mutator.reset(capture);
+        assert_eq!(mutator.base.total_change.get(), Decimal::new(35, 0));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn projection_parallel() {
+        let factory = |idx: usize| -> Box<dyn Mutator> {
+            let base = |target_idx, change, cycle| MutatorBase::new(idx, target_idx, change, Decimal::ZERO, true, cycle, 0);
+
+            match idx {
+                0 => Box::new(StandardMutator(base(0, Decimal::new(10, 0), 10))), 
+                1 => Box::new(PercentageMutator::new(base(1, Decimal::new(5, 2), 3))), 
+                2 => Box::new(crate::TransferMutator::new(base(2, Decimal::new(1, 1), 20), 4)), 
+                3 => Box::new(CompoundMutator::new(base(5, Decimal::new(1, 1), 20), Decimal::new(30, 0))), 
+                9 => Box::new(StandardMutator(base(3, Decimal::new(-150, 0), 10))), 
+                _ => Box::new(StandardMutator(base(idx % 6, Decimal::ONE, 5)))
+            }
+        };
+        let build = || {
+            let asset_pool = AssetPool::with_policy(crate::MutationPolicy::ClampAtZero);
+            asset_pool.set_scale(Some(1));
+            let mutator_pool = MutatorPool::new();
+
+            (1..=6).for_each(|value| { asset_pool.load(Asset::new(Decimal::new(value * 100, 0))); });
+            (0..10).for_each(|idx| { mutator_pool.load(factory(idx)); });
+            mutator_pool.set_active(8, false);
+
+            Modeller::new(asset_pool, mutator_pool)
+        };
+        let table = |result: &crate::ResultPacket| -> Vec<(u64, Vec<Decimal>)> {
+            result.interval_points
+                .iter()
+                .map(|point| (point.time_pos, point.asset_captures.iter().map(|cap| cap.value).collect()))
+                .collect()
+        };
+
+        let serial = build();
+        let expected = serial.project(20, 10, 4, 5, None).unwrap();
+
+        // Split into a fixed number of parts, so the assets are split
+        // however many threads the test runs on.
+        let parallel = build();
+        let result = parallel.project_in_parts(20, 10, 4, 5, &[vec![2, 4]], factory, 4).unwrap();
+
+        assert_eq!(table(&result), table(&expected));
+        assert_eq!(result.metrics().events_triggered(), expected.metrics().events_triggered());
+        assert_eq!(result.metrics().peak_events(), expected.metrics().peak_events());
+        assert!(parallel.asset_pool.iter_values().eq(serial.asset_pool.iter_values()));
+
+        // Without the group, the transfer's source and target are
+        // projected apart, so the source is never debited.
+        let ungrouped = build().project_in_parts(20, 10, 4, 5, &[], factory, 4).unwrap();
+        assert_ne!(table(&ungrouped), table(&expected));
+
+        let limited = build();
+        limited.asset_pool.set_limits(MutationLimits::new(None, Some(Decimal::new(650, 0))));
+        assert_eq!(
+            limited.project_in_parts(20, 10, 4, 5, &[vec![2, 4]], factory, 4).err(), 
+            Some(ProjectionError::Trigger(TriggerError::MutationRejected(5)))
+        );
+
+        let mut limited = build();
+        limited.max_events = Some(expected.interval_points.len());
+        assert!(matches!(
+            limited.project_in_parts(20, 10, 4, 5, &[], factory, 4), 
+            Err(ProjectionError::EventLimitExceeded { .. })
+        ));
+        assert_eq!(Modeller::partition_assets(6, &[vec![2, 4]], 4), vec![0, 0, 1, 1, 1, 2]);
+        assert_eq!(Modeller::partition_assets(3, &[vec![0, 1], vec![1, 2]], 3), vec![0, 0, 0]);
+    }
+
+    /// Compares `project_parallel` against `project` over 10,000 assets.
+    /// Run with `cargo test --release --features rayon -- --ignored --nocapture`.
+    #[cfg(feature = "rayon")]
+    #[test]
+    #[ignore]
+    fn projection_parallel_timing() {
+        const ASSETS: usize = 10_000;
+
+        let factory = |idx: usize| -> Box<dyn Mutator> {
+            Box::new(StandardMutator(MutatorBase::new(idx, idx, Decimal::ONE, Decimal::ZERO, true, 1, 0)))
+        };
+        let build = || {
+            let asset_pool = AssetPool::new();
+            let mutator_pool = MutatorPool::new();
+
+            for idx in 0..ASSETS {
+                asset_pool.load(Asset::new(Decimal::new(100, 0)));
+                mutator_pool.load(factory(idx));
+            }
+
+            Modeller::new(asset_pool, mutator_pool)
+        };
+
+        let modeller = build();
+        let started = std::time::Instant::now();
+        modeller.project(0, 100, 10, 0, None).unwrap();
+        let serial_time = started.elapsed();
+
+        let modeller = build();
+        let started = std::time::Instant::now();
+        modeller.project_parallel(0, 100, 10, 0, &[], factory).unwrap();
+        let parallel_time = started.elapsed();
+
+        println!(
+            "{} assets on {} threads: project {:?}, project_parallel {:?}", 
+            ASSETS, rayon::current_num_threads(), serial_time, parallel_time
+        );
+    }
+}
+
+/// Conformance checks for `Mutator` implementations.
+#[cfg(feature = "testing")]
+pub mod testing {
+    use rust_decimal::Decimal;
+
+    use crate::Mutator;
+
+    /// Captures `mutator`, triggers it a few times, then resets it,
+    /// panicking if its base's `total_change` does not return to the
+    /// captured value.
+    pub fn assert_capture_roundtrip(mutator: &mut dyn Mutator) {
+        let capture = mutator.capture();
+        let expected = capture.base().total_change();
+
+        (0..3).fold(Decimal::ONE_HUNDRED, |value, _| mutator.on_event(value));
+        mutator.reset(capture);
+
+        assert_eq!(
+            mutator.borrow_base().total_change.get(), expected, 
+            "total_change was not restored by reset"
+        );
+    }
+}
+
+/// The value of an asset at its idx.
+/// 
+/// Two captures are equal, and hash alike, only if both their value and
+/// idx match, so captures of different assets with the same value stay
+/// distinct in a `HashSet`. Captures are ordered by value, with ties
+/// broken by idx. To key captures by asset alone, use `idx` as the key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetCapture {
+    value: Decimal,
+    idx: usize,
+}
+
+impl AssetCapture {
+    pub fn value(&self) -> Decimal {
+        self.value
+    }
+
+    pub fn idx(&self) -> usize {
+        self.idx
+    }
+}
+
+impl Ord for AssetCapture {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.value, self.idx).cmp(&(other.value, other.idx))
+    }
+}
+
+impl PartialOrd for AssetCapture {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct AccountCapture {
+    asset_id: Vec<usize>,
+    idx: usize
+}
+
+pub struct Account {
+    idx: usize,
+    asset_ids: Vec<usize>,
+    asset_pool: Rc<AssetPool>
+}
+
+impl Account {
+    pub fn new(idx: usize, asset_pool: Rc<AssetPool>) -> Account {
+        Account { idx, asset_ids: vec![], asset_pool }
+    }
+
+    pub fn capture(&self) -> AccountCapture {
+        AccountCapture { asset_id: self.asset_ids.clone(), idx: self.idx }
+    }
+
+    pub fn reload(capture: AccountCapture, asset_pool: Rc<AssetPool>) -> Account {
+        Account { idx: capture.idx, asset_ids: capture.asset_id, asset_pool }
+    }
+
+    pub fn total_value(&self) -> Decimal {
+        self.asset_ids
+            .iter()
+            .fold(Decimal::ZERO, |accum, next_id| {
+                accum + self.asset_pool
+                    .get_raw(*next_id)
+                    .unwrap_or(Decimal::ZERO)
+            })
+    }
+}
+
+/// A handle to an asset, returned when it is loaded into an `AssetPool`
+/// or `SyncAssetPool`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AssetId(usize);
+
+impl AssetId {
+    /// Returns the underlying idx of the asset in its `AssetPool`.
+    pub fn raw(self) -> usize {
+        self.0
+    }
+}
+
+/// A handle to a mutator, returned when it is loaded into a `MutatorPool`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MutatorId(usize);
+
+impl MutatorId {
+    /// Returns the underlying idx of the mutator in its `MutatorPool`.
+    pub fn raw(self) -> usize {
+        self.0
+    }
+}
+
+pub struct Asset {
+    value: Cell<Decimal>,
+}
+
+impl std::fmt::Debug for Asset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Asset").field(&self.value.get()).finish()
+    }
+}
+
+impl Asset {
+    pub fn new(value: Decimal) -> Asset {
+        Asset { value: Cell::new(value) }
+    }
+
+    pub fn mutate(&self, amount: Decimal) {
+        self.value.set(self.value.get() + amount);
+    }
+
+    /// Like `mutate`, but rejects an amount, or a resulting value, outside
+    /// of `limits`, such as one left by converting a bad `f64`.
+    /// The value is left unchanged on error.
+    pub fn mutate_checked(&self, amount: Decimal, limits: &MutationLimits) -> Result<(), MutateError> {
+        limits.check(amount)?;
+
+        let value = self.value.get()
+            .checked_add(amount)
+            .ok_or(MutateError::Overflow)?;
+
+        limits.check(value)?;
+        self.value.set(value);
+
+        Ok(())
+    }
+}
+
+/// Bounds on the amounts and values an asset accepts, for catching
+/// implausible values before they spread through a model.
+/// Defaults to no limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MutationLimits {
+    /// The most decimal places a value may have.
+    pub max_scale: Option<u32>,
+    /// The magnitude a value must stay below.
+    pub max_magnitude: Option<Decimal>
+}
+
+impl MutationLimits {
+    pub fn new(max_scale: Option<u32>, max_magnitude: Option<Decimal>) -> MutationLimits {
+        MutationLimits { max_scale, max_magnitude }
+    }
+
+    /// Returns an error describing the first limit `value` breaks, if any.
+    pub fn check(&self, value: Decimal) -> Result<(), MutateError> {
+        if self.max_scale.is_some_and(|max_scale| value.scale() > max_scale) {
+            return Err(MutateError::ScaleTooLarge(value.scale()));
+        }
+
+        if self.max_magnitude.is_some_and(|max_magnitude| value.abs() >= max_magnitude) {
+            return Err(MutateError::MagnitudeTooLarge(value));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MutateError {
+    AssetNotFound(usize),
+    ScaleTooLarge(u32),
+    MagnitudeTooLarge(Decimal),
+    Overflow,
+    /// The `MutationPolicy` rejected the new value.
+    Rejected
+}
+
+impl std::fmt::Display for MutateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MutateError::AssetNotFound(idx) => write!(f, "no asset with idx {}", idx),
+            MutateError::ScaleTooLarge(scale) => write!(f, "value has {} decimal places", scale),
+            MutateError::MagnitudeTooLarge(value) => write!(f, "{} is too large in magnitude", value),
+            MutateError::Overflow => write!(f, "mutation overflowed"),
+            MutateError::Rejected => write!(f, "mutation was rejected by the policy")
+        }
+    }
+}
+
+impl std::error::Error for MutateError {}
+
+/// Controls how an `AssetPool` handles a mutation that would leave
+/// an asset with a negative value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MutationPolicy {
+    #[default]
+    Allow,
+    /// The asset's value is set to zero instead.
+    ClampAtZero,
+    /// The mutation is rejected, leaving the asset unchanged.
+    ErrorOnNegative
+}
+
+impl MutationPolicy {
+    /// Returns the value an asset should take on, or `None` if
+    /// the mutation should be rejected.
+    fn apply(self, value: Decimal) -> Option<Decimal> {
+        match self {
+            MutationPolicy::Allow => Some(value),
+            MutationPolicy::ClampAtZero => Some(value.max(Decimal::ZERO)),
+            MutationPolicy::ErrorOnNegative if value < Decimal::ZERO => None,
+            MutationPolicy::ErrorOnNegative => Some(value)
+        }
+    }
+}
+
+/// Holds the assets of a model, addressed by idx.
+/// 
+/// **Warning:** the assets sit behind a `RefCell`, so any method that
+/// loads, unloads or removes assets panics if called while the pool is
+/// still borrowed, such as from within an `apply` closure or while an
+/// `iter_values` iterator is alive. Use `read_snapshot` to work with the
+/// values without holding a borrow.
+/// 
+/// `AssetPool::default()` creates a bare, empty `AssetPool` with the
+/// `Allow` policy, for use where an `Rc` is not wanted, such as a field
+/// of a struct deriving `Default`.
+#[derive(Default)]
+pub struct AssetPool {
+    assets: RefCell<Vec<Asset>>,
+    policy: Cell<MutationPolicy>,
+    scale: Cell<Option<u32>>,
+    limits: Cell<MutationLimits>,
+    tags: RefCell<HashMap<String, Vec<usize>>>
+}
+
+impl AssetPool {
+    pub fn new() -> Rc<AssetPool> {
+        AssetPool::with_policy(MutationPolicy::Allow)
+    }
+
+    pub fn with_policy(policy: MutationPolicy) -> Rc<AssetPool> {
+        Rc::new(AssetPool { 
+            assets: RefCell::new(Vec::new()), 
+            policy: Cell::new(policy), 
+            scale: Cell::new(None), 
+            limits: Cell::new(MutationLimits::default()), 
+            tags: RefCell::new(HashMap::new()) 
+        })
+    }
+
+    /// Creates an `AssetPool` able to load `capacity` assets without reallocating.
+    pub fn with_capacity(capacity: usize) -> Rc<AssetPool> {
+        Rc::new(AssetPool { 
+            assets: RefCell::new(Vec::with_capacity(capacity)), 
+            policy: Cell::new(MutationPolicy::Allow), 
+            scale: Cell::new(None), 
+            limits: Cell::new(MutationLimits::default()), 
+            tags: RefCell::new(HashMap::new()) 
+        })
+    }
+
+    /// Wraps a bare `AssetPool`, such as one from `AssetPool::default()`,
+    /// in an `Rc`, as `new` returns it.
+    pub fn into_rc(self) -> Rc<AssetPool> {
+        Rc::new(self)
+    }
+
+    /// Reserves capacity for at least `additional` more assets.
+    pub fn reserve(&self, additional: usize) {
+        self.assets.borrow_mut().reserve(additional)
+    }
+
+    pub fn policy(&self) -> MutationPolicy {
+        self.policy.get()
+    }
+
+    pub fn set_policy(&self, policy: MutationPolicy) {
+        self.policy.set(policy)
+    }
+
+    /// The number of decimal places mutated values are rounded to,
+    /// or `None` if they are left unrounded.
+    pub fn scale(&self) -> Option<u32> {
+        self.scale.get()
+    }
+
+    /// Sets the number of decimal places mutated values are rounded to.
+    /// Values already held are left unchanged until they are next mutated.
+    pub fn set_scale(&self, scale: Option<u32>) {
+        self.scale.set(scale)
+    }
+
+    pub fn limits(&self) -> MutationLimits {
+        self.limits.get()
+    }
+
+    /// Sets the `MutationLimits` every mutation is checked against. Both the
+    /// change and the new value are checked after rounding to the scale, and
+    /// before the `MutationPolicy` is applied. A mutation breaking the limits
+    /// is rejected, leaving the asset unchanged.
+    pub fn set_limits(&self, limits: MutationLimits) {
+        self.limits.set(limits)
+    }
+
+    /// Creates an `AssetPool` with an asset for each value,
+    /// so that idx `i` holds the `i`th value.
+    pub fn from_values(values: impl IntoIterator<Item = Decimal>) -> Rc<AssetPool> {
+        Rc::new(AssetPool { 
+            assets: RefCell::new(values.into_iter().map(Asset::new).collect()), 
+            policy: Cell::new(MutationPolicy::Allow), 
+            scale: Cell::new(None), 
+            limits: Cell::new(MutationLimits::default()), 
+            tags: RefCell::new(HashMap::new()) 
+        })
+    }
+
+    pub fn load(&self, asset: Asset) -> AssetId {
+        let mut assets = self.assets.borrow_mut();
+        assets.push(asset);
+
+        AssetId(assets.len() - 1)
+    }
+
+    /// Loads every asset in order, borrowing the `AssetPool` once,
+    /// and returns the range of idx they were given.
+    pub fn load_batch(&self, assets: impl IntoIterator<Item = Asset>) -> std::ops::Range<usize> {
+        let mut pool = self.assets.borrow_mut();
+        let start = pool.len();
+        pool.extend(assets);
+
+        start..pool.len()
+    }
+
+    /// Returns the value of the asset at `idx`, first loading an asset
+    /// valued by `default` at `idx` if the `AssetPool` is too short.
+    /// 
+    /// **Warning:** any idx between the last asset and `idx` is filled
+    /// with a zero-valued asset, keeping indices contiguous for `reload`.
+    pub fn get_or_insert_with(&self, idx: usize, default: impl FnOnce() -> Decimal) -> Decimal {
+        if idx >= self.len() {
+            let value = default();
+            let mut assets = self.assets.borrow_mut();
+
+            assets.resize_with(idx, || Asset::new(Decimal::ZERO));
+            assets.push(Asset::new(value));
+        }
+
+        self.assets.borrow()[idx].value.get()
+    }
+
+    /// Loads `asset` only if its value lies within `bounds`,
+    /// otherwise returning the value.
+    pub fn try_load(&self, asset: Asset, bounds: std::ops::RangeInclusive<Decimal>) -> Result<AssetId, Decimal> {
+        let value = asset.value.get();
+
+        if bounds.contains(&value) {
+            Ok(self.load(asset))
+        } else { Err(value) }
+    }
+
+    pub fn get(&self, id: AssetId) -> Option<Decimal> {
+        self.get_raw(id.0)
+    }
+
+    pub fn get_raw(&self, idx: usize) -> Option<Decimal> {
+        self.assets.borrow().get(idx).map(|asset| asset.value.get())
+    }
+
+    /// # Safety
+    /// 
+    /// `idx` must be in bounds of the `AssetPool`.
+    pub unsafe fn get_unchecked(&self, idx: usize) -> Decimal {
+        self.assets.borrow()
+        .get_unchecked(idx)
+        .value
+        .get()
+    }
+
+    /// Adds `change` to the asset, subject to the `AssetPool`'s
+    /// `MutationPolicy`. Returns false if there is no such asset, or
+    /// if the policy rejected the mutation.
+    pub fn mutate(&self, id: AssetId, change: Decimal) -> bool {
+        self.mutate_raw(id.0, change)
+    }
+
+    /// As `mutate`, but for the asset at the raw `idx`.
+    pub fn mutate_raw(&self, idx: usize, change: Decimal) -> bool {
+        self.mutate_checked(AssetId(idx), change).is_ok()
+    }
+
+    /// Like `mutate`, but returns why a mutation was not applied. Both
+    /// `change` and the new value are rounded to the scale, then checked
+    /// against the `MutationLimits`.
+    pub fn mutate_checked(&self, id: AssetId, change: Decimal) -> Result<(), MutateError> {
+        let assets = self.assets.borrow();
+        let asset = assets.get(id.0).ok_or(MutateError::AssetNotFound(id.0))?;
+
+        asset.value.set(self.settle_change(asset.value.get(), change)?);
+        Ok(())
+    }
+
+    /// Sets the value of the asset, returning its previous value, or `None`
+    /// if there is no such asset. The value is set as given, bypassing the
+    /// `MutationPolicy` and scale.
+    pub fn replace(&self, id: AssetId, value: Decimal) -> Option<Decimal> {
+        self.assets.borrow()
+            .get(id.0)
+            .map(|asset| asset.value.replace(value))
+    }
+
+    /// Replaces the value of the asset with the result of `f`, subject
+    /// to the `MutationPolicy`. Returns false if there is no such asset
+    /// or the result was rejected.
+    /// 
+    /// **Warning:** the `AssetPool` is borrowed while `f` runs, so `f`
+    /// must not load, unload or remove assets.
+    pub fn apply<F: FnOnce(Decimal) -> Decimal>(&self, id: AssetId, f: F) -> bool {
+        if let Some(asset) = self.assets.borrow().get(id.0) {
+            if let Some(value) = self.settle(f(asset.value.get())) {
+                asset.value.set(value);
+                true
+            } else { false }
+        } else { false }
+    }
+
+    /// # Safety
+    /// 
+    /// `idx` must be in bounds of the `AssetPool`.
+    pub unsafe fn mutate_unchecked(&self, idx: usize, change: Decimal) {
+        self.mutate_with_policy(self.assets.borrow().get_unchecked(idx), change);
+    }
+
+    /// Adds each change to the asset at its idx, in order, borrowing the
+    /// `AssetPool` once. Either every update is applied or none are.
+    /// 
+    /// Returns the idx of the first update that is out of bounds, or
+    /// failing that, the first update that overflows or is rejected by the
+    /// `MutationLimits` or `MutationPolicy`, as `mutate_checked` would.
+    pub fn mutate_many(&self, updates: &[(usize, Decimal)]) -> Result<(), usize> {
+        let assets = self.assets.borrow();
+
+        if let Some((idx, _)) = updates.iter().find(|(idx, _)| *idx >= assets.len()) {
+            return Err(*idx);
+        }
+
+        let mut pending: Vec<(usize, Decimal)> = Vec::with_capacity(updates.len());
+
+        for (idx, change) in updates {
+            let current = pending.iter()
+                .rev()
+                .find(|(pending_idx, _)| pending_idx == idx)
+                .map_or_else(|| assets[*idx].value.get(), |(_, value)| *value);
+
+            pending.push((*idx, self.settle_change(current, *change).map_err(|_| *idx)?));
+        }
+
+        pending.into_iter().for_each(|(idx, value)| assets[idx].value.set(value));
+
+        Ok(())
+    }
+
+    /// Multiplies the value of every asset by `factor`, borrowing the
+    /// `AssetPool` once. Values are rounded to the scale, but bypass the
+    /// `MutationPolicy`, so a conversion is never partially applied.
+    pub fn scale_all(&self, factor: Decimal) {
+        self.map_all(|value| value * factor)
+    }
+
+    /// Adds `delta` to the value of every asset, as `scale_all` does.
+    pub fn offset_all(&self, delta: Decimal) {
+        self.map_all(|value| value + delta)
+    }
+
+    fn map_all<F: Fn(Decimal) -> Decimal>(&self, f: F) {
+        let scale = self.scale.get();
+
+        for asset in self.assets.borrow().iter() {
+            let value = f(asset.value.get());
+            asset.value.set(scale.map_or(value, |scale| value.round_dp(scale)));
+        }
+    }
+
+    fn mutate_with_policy(&self, asset: &Asset, change: Decimal) -> bool {
+        if let Ok(value) = self.settle_change(asset.value.get(), change) {
+            asset.value.set(value);
+            true
+        } else { false }
+    }
+
+    /// Rounds `value` to the `AssetPool`'s scale, then returns the value
+    /// an asset should take on under its `MutationPolicy`.
+    fn settle(&self, value: Decimal) -> Option<Decimal> {
+        self.settle_checked(value).ok()
+    }
+
+    /// As `settle`, but checks the rounded value against the
+    /// `MutationLimits` and returns why it was rejected.
+    fn settle_checked(&self, value: Decimal) -> Result<Decimal, MutateError> {
+        let value = match self.scale.get() {
+            Some(scale) => value.round_dp(scale),
+            None => value
+        };
+
+        self.limits.get().check(value)?;
+        self.policy.get().apply(value).ok_or(MutateError::Rejected)
+    }
+
+    /// Returns the value an asset holding `value` should take on after
+    /// adding `change`. The change is rounded to the `AssetPool`'s scale
+    /// and checked against the `MutationLimits`, then the new value is
+    /// settled as by `settle_checked`.
+    fn settle_change(&self, value: Decimal, change: Decimal) -> Result<Decimal, MutateError> {
+        let rounded = match self.scale.get() {
+            Some(scale) => change.round_dp(scale),
+            None => change
+        };
+
+        self.limits.get().check(rounded)?;
+
+        let value = value.checked_add(change).ok_or(MutateError::Overflow)?;
+        self.settle_checked(value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.assets.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.assets.borrow().is_empty()
+    }
+
+    /// Returns an owned copy of the values of all assets in idx order,
+    /// holding no borrow of the `AssetPool` once it returns.
+    pub fn read_snapshot(&self) -> Vec<Decimal> {
+        self.assets.borrow()
+            .iter()
+            .map(|asset| asset.value.get())
+            .collect()
+    }
+
+    /// Iterates over the values of all assets in idx order.
+    /// The `AssetPool` stays borrowed for the lifetime of the iterator,
+    /// so it must not be loaded into or unloaded until the iterator is dropped.
+    pub fn iter_values(&self) -> impl Iterator<Item = Decimal> + '_ {
+        AssetValues { assets: self.assets.borrow(), idx: 0 }
+    }
+
+    pub fn total_value(&self) -> Decimal {
+        self.assets.borrow()
+            .iter()
+            .fold(Decimal::ZERO, |accum, asset| accum + asset.value.get())
+    }
+
+    /// Returns `None` if the `AssetPool` is empty.
+    pub fn min_value(&self) -> Option<Decimal> {
+        self.assets.borrow()
+            .iter()
+            .map(|asset| asset.value.get())
+            .min()
+    }
+
+    /// Returns `None` if the `AssetPool` is empty.
+    pub fn max_value(&self) -> Option<Decimal> {
+        self.assets.borrow()
+            .iter()
+            .map(|asset| asset.value.get())
+            .max()
+    }
+
+    /// Returns `None` if the `AssetPool` is empty.
+    pub fn mean_value(&self) -> Option<Decimal> {
+        let assets = self.assets.borrow();
+
+        if assets.is_empty() {
+            return None;
+        }
+
+        let total = assets
+            .iter()
+            .fold(Decimal::ZERO, |accum, asset| accum + asset.value.get());
+
+        Some(total / Decimal::from(assets.len()))
+    }
+
+    /// Returns the values of the assets at the given indices, in order.
+    /// Returns `None` if any index is out of bounds.
+    pub fn get_many(&self, idxs: &[usize]) -> Option<Vec<Decimal>> {
+        let assets = self.assets.borrow();
+
+        idxs.iter()
+            .map(|idx| assets.get(*idx).map(|asset| asset.value.get()))
+            .collect()
+    }
+
+    /// Sums the values of the assets at the given indices.
+    /// Returns `None` if any index is out of bounds.
+    pub fn value_of_group(&self, idxs: &[usize]) -> Option<Decimal> {
+        let assets = self.assets.borrow();
+        let mut accum = Decimal::ZERO;
+
+        for idx in idxs {
+            if let Some(asset) = assets.get(*idx) {
+                accum += asset.value.get();
+            } else { return None; }
+        }
+
+        Some(accum)
+    }
+
+    /// Returns the total value of the assets with an idx within `range`,
+    /// or `None` if the range extends past the end of the `AssetPool`.
+    pub fn sum_range(&self, range: std::ops::Range<usize>) -> Option<Decimal> {
+        self.assets.borrow()
+            .get(range)
+            .map(|assets| assets.iter().fold(Decimal::ZERO, |accum, asset| accum + asset.value.get()))
+    }
+
+    /// Tags the asset at `idx` with `label`, so that it counts towards
+    /// `value_of_tag`. An asset may have any number of tags.
+    /// Returns false if there is no asset at `idx`.
+    pub fn tag(&self, idx: usize, label: &str) -> bool {
+        if idx >= self.len() {
+            return false;
+        }
+
+        let mut tags = self.tags.borrow_mut();
+        let tagged = tags.entry(label.to_string()).or_default();
+
+        if !tagged.contains(&idx) {
+            tagged.push(idx);
+        }
+
+        true
+    }
+
+    /// Returns the total value of all assets tagged with `label`,
+    /// or zero if no asset has the tag.
+    pub fn value_of_tag(&self, label: &str) -> Decimal {
+        let assets = self.assets.borrow();
+
+        self.tags.borrow()
+            .get(label)
+            .map_or(Decimal::ZERO, |tagged| {
+                tagged.iter().fold(Decimal::ZERO, |accum, idx| accum + assets[*idx].value.get())
+            })
+    }
+
+    /// Moves every tagged idx to the one given by `remap`,
+    /// or untags it if `remap` returns `None`.
+    fn retag(&self, remap: impl Fn(usize) -> Option<usize>) {
+        for tagged in self.tags.borrow_mut().values_mut() {
+            *tagged = tagged.iter().filter_map(|idx| remap(*idx)).collect();
+        }
+    }
+
+    /// Removes and returns the asset at `idx`, replacing it with the
+    /// last asset of the `AssetPool`.
+    /// 
+    /// **Warning:** the last asset's idx becomes `idx`, so any
+    /// `MutatorBase::target_idx` referencing the last asset must be
+    /// updated. All other indices are left untouched.
+    pub fn remove(&self, idx: usize) -> Option<Asset> {
+        let mut assets = self.assets.borrow_mut();
+
+        if idx < assets.len() {
+            let last_idx = assets.len() - 1;
+            self.retag(|tagged| match tagged {
+                tagged if tagged == idx => None,
+                tagged if tagged == last_idx => Some(idx),
+                tagged => Some(tagged)
+            });
+
+            Some(assets.swap_remove(idx))
+        } else { None }
+    }
+
+    /// Removes and returns the asset at `idx`, shifting every asset
+    /// after it down by one, along with a remapping table of
+    /// `(old_idx, new_idx)` for each shifted asset.
+    /// 
+    /// **Warning:** every idx greater than `idx` is invalidated,
+    /// so any `MutatorBase::target_idx` referencing one must be patched
+    /// using the returned table.
+    pub fn remove_stable(&self, idx: usize) -> Option<(Asset, Vec<(usize, usize)>)> {
+        let mut assets = self.assets.borrow_mut();
+
+        if idx < assets.len() {
+            let asset = assets.remove(idx);
+            self.retag(|tagged| match tagged {
+                tagged if tagged == idx => None,
+                tagged if tagged > idx => Some(tagged - 1),
+                tagged => Some(tagged)
+            });
+
+            let remap = (idx..assets.len())
+                .map(|new_idx| (new_idx + 1, new_idx))
+                .collect();
+
+            Some((asset, remap))
+        } else { None }
+    }
+
+    /// Appends copies of all of `other`'s assets in idx order, returning the
+    /// idx each was given, so that the `i`th returned idx belongs to `other`'s
+    /// asset at idx `i`. Every existing idx of this `AssetPool` is preserved,
+    /// and `other` retains its assets.
+    pub fn merge(&self, other: &AssetPool) -> Vec<usize> {
+        let values: Vec<Decimal> = other.iter_values().collect();
+        let mut assets = self.assets.borrow_mut();
+        let first = assets.len();
+
+        assets.extend(values.into_iter().map(Asset::new));
+
+        (first..assets.len()).collect()
+    }
+
+    /// Creates an independent `AssetPool` holding copies of all assets in
+    /// idx order, with the same `MutationPolicy`, scale, limits and tags.
+    pub fn fork(&self) -> Rc<AssetPool> {
+        let assets = self.assets.borrow()
+            .iter()
+            .map(|asset| Asset::new(asset.value.get()))
+            .collect();
+
+        Rc::new(AssetPool { 
+            assets: RefCell::new(assets), 
+            policy: Cell::new(self.policy.get()), 
+            scale: Cell::new(self.scale.get()), 
+            limits: Cell::new(self.limits.get()), 
+            tags: RefCell::new(self.tags.borrow().clone()) 
+        })
+    }
+
+    /// Writes one `idx,value` row per asset, in idx order.
+    #[cfg(feature = "csv")]
+    pub fn to_csv<W: std::io::Write>(&self, w: W) -> Result<(), CsvError> {
+        let mut writer = csv::WriterBuilder::new().has_headers(false).from_writer(w);
+
+        for (idx, value) in self.iter_values().enumerate() {
+            writer.write_record([idx.to_string(), value.to_string()])?;
+        }
+
+        writer.flush().map_err(|err| CsvError::Csv(err.into()))
+    }
+
+    /// Reads `idx,value` rows into a new `AssetPool`, in idx order.
+    /// Returns `CsvError::Reload` if the idx column is not contiguous from 0.
+    #[cfg(feature = "csv")]
+    pub fn from_csv<R: std::io::Read>(r: R) -> Result<Rc<AssetPool>, CsvError> {
+        let mut reader = csv::ReaderBuilder::new().has_headers(false).from_reader(r);
+        let mut captures = Vec::new();
+
+        for (line, record) in reader.records().enumerate() {
+            let record = record?;
+            let parse_err = || CsvError::Parse { line: line + 1 };
+
+            if record.len() != 2 {
+                return Err(parse_err());
+            }
+
+            let idx = record[0].trim().parse().map_err(|_| parse_err())?;
+            let value = record[1].trim().parse().map_err(|_| parse_err())?;
+
+            captures.push(AssetCapture { value, idx });
+        }
+
+        Ok(AssetPool::reload(captures)?)
+    }
+
+    /// Removes and returns the assets from the `AssetPool`.
+    /// The `AssetPool`'s assets are replaced with an empty vector.
+    pub fn unload(&self) -> Vec<Asset> {
+        self.tags.borrow_mut().clear();
+        self.assets.replace(Vec::new())
+    }
+
+    /// Creates captures of all assets owned by the given `AssetPool`.
+    /// The given `AssetPool` retains all of its assets.
+    /// 
+    /// Captures are always in ascending idx order, so they can be passed
+    /// straight to `reload_unchecked`.
+    pub fn capture(&self) -> Vec<AssetCapture> {
+        let mut out = Vec::new();
+        self.capture_into(&mut out);
+
+        out
+    }
+
+    /// Clears `buf`, then fills it with captures of all assets owned by
+    /// the given `AssetPool` in ascending idx order, reusing `buf`'s allocation.
+    /// The given `AssetPool` retains all of its assets.
+    pub fn capture_into(&self, buf: &mut Vec<AssetCapture>) {
+        let assets = self.assets.borrow();
+        buf.clear();
+        buf.reserve(assets.len());
+
+        for idx in 0..assets.len() {
+            buf.push( AssetCapture { value: unsafe {
+                assets.get_unchecked(idx).value.get()
+            }, idx } );
+        }
+    }
+
+    /// Creates captures of just the assets at the given indices, in the
+    /// order given, each keeping its idx in the `AssetPool`.
+    /// Returns `None` if any index is out of bounds.
+    pub fn capture_subset(&self, idxs: &[usize]) -> Option<Vec<AssetCapture>> {
+        let assets = self.assets.borrow();
+
+        idxs.iter()
+            .map(|&idx| assets.get(idx).map(|asset| AssetCapture { value: asset.value.get(), idx }))
+            .collect()
+    }
+
+    /// Sorts the given captures by idx, and then converts all
+    /// captures into assets, which are then given to the returned
+    /// `AssetPool`.
+    /// 
+    /// Returns `ReloadError::NonContiguous` if the sorted idx sequence
+    /// is not `0..n`.
+    pub fn reload(mut captures: Vec<AssetCapture>) -> Result<Rc<AssetPool>, ReloadError> {
+        captures.sort_unstable_by_key(|cap| cap.idx);
+
+        for (expected, cap) in captures.iter().enumerate() {
+            if cap.idx != expected {
+                return Err(ReloadError::NonContiguous { expected, found: cap.idx });
+            }
+        }
+
+        Ok(AssetPool::reload_unchecked(captures))
+    }
+
+    /// Returns `(idx, after - before)` for each idx whose value differs
+    /// between the two sets of captures, in ascending idx order.
+    /// An idx missing from either set is treated as having a value of zero.
+    pub fn diff(before: &[AssetCapture], after: &[AssetCapture]) -> Vec<(usize, Decimal)> {
+        let mut deltas: BTreeMap<usize, Decimal> = BTreeMap::new();
+
+        for cap in after {
+            *deltas.entry(cap.idx).or_default() += cap.value;
+        }
+
+        for cap in before {
+            *deltas.entry(cap.idx).or_default() -= cap.value;
+        }
+
+        deltas.into_iter()
+            .filter(|(_, delta)| !delta.is_zero())
+            .collect()
+    }
+
+    /// Converts all captures into assets, which are then given
+    /// to the returned `AssetPool`.
+    /// 
+    /// **Warning:** if captures are not sorted by idx, the returned
+    /// `AssetPool` will not function properly
+    pub fn reload_unchecked(captures: Vec<AssetCapture>) -> Rc<AssetPool> {
+        let out = AssetPool::new();
+
+        captures.into_iter().for_each(|cap| {
+            out.load(Asset::new(cap.value));
+        });
+
+        out
+    }
+}
+
+/// A thread-safe counterpart to `AssetPool`, for sharing assets
+/// across projections running on separate threads.
+/// 
+/// Every operation locks a `Mutex`, which costs noticeably more than
+/// the `RefCell` borrow of an `AssetPool`, so prefer `AssetPool`
+/// unless the pool must cross threads.
+/// 
+/// A `SyncAssetPool` has no `MutationPolicy`, scale or `MutationLimits`,
+/// so every mutation is applied as given.
+pub struct SyncAssetPool {
+    assets: Mutex<Vec<Asset>>
+}
+
+impl SyncAssetPool {
+    pub fn new() -> Arc<SyncAssetPool> {
+        Arc::new(SyncAssetPool { assets: Mutex::new(Vec::new()) })
+    }
+
+    pub fn load(&self, asset: Asset) -> AssetId {
+        let mut assets = self.lock();
+        assets.push(asset);
+
+        AssetId(assets.len() - 1)
+    }
+
+    pub fn get(&self, id: AssetId) -> Option<Decimal> {
+        self.get_raw(id.0)
+    }
+
+    pub fn get_raw(&self, idx: usize) -> Option<Decimal> {
+        self.lock().get(idx).map(|asset| asset.value.get())
+    }
+
+    /// Adds `change` to the asset. Unlike `AssetPool::mutate`, no policy,
+    /// scale or limits apply. Returns false if there is no such asset.
+    pub fn mutate(&self, id: AssetId, change: Decimal) -> bool {
+        self.mutate_raw(id.0, change)
+    }
+
+    /// As `mutate`, but for the asset at the raw `idx`.
+    pub fn mutate_raw(&self, idx: usize, change: Decimal) -> bool {
+        if let Some(asset) = self.lock().get(idx) {
+            asset.mutate(change);
+            true
+        } else { false }
+    }
+
+    /// Creates captures of all assets owned by the given `SyncAssetPool`.
+    /// The given `SyncAssetPool` retains all of its assets.
+    pub fn capture(&self) -> Vec<AssetCapture> {
+        self.lock()
+            .iter()
+            .enumerate()
+            .map(|(idx, asset)| AssetCapture { value: asset.value.get(), idx })
+            .collect()
+    }
+
+    /// A panic while the lock is held cannot leave an asset half-written,
+    /// so a poisoned lock is recovered rather than propagated.
+    fn lock(&self) -> MutexGuard<'_, Vec<Asset>> {
+        self.assets.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+struct AssetValues<'a> {
+    assets: Ref<'a, Vec<Asset>>,
+    idx: usize
+}
+
+impl Iterator for AssetValues<'_> {
+    type Item = Decimal;
+
+    fn next(&mut self) -> Option<Decimal> {
+        let value = self.assets.get(self.idx)?.value.get();
+        self.idx += 1;
+
+        Some(value)
+    }
+}
+
+#[cfg(feature = "csv")]
+#[derive(Debug)]
+pub enum CsvError {
+    Csv(csv::Error),
+    /// The row at `line`, counting from 1, is not a valid `idx,value` pair.
+    Parse { line: usize },
+    Reload(ReloadError)
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for CsvError {
+    fn from(err: csv::Error) -> Self {
+        CsvError::Csv(err)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<ReloadError> for CsvError {
+    fn from(err: ReloadError) -> Self {
+        CsvError::Reload(err)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl std::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::Csv(err) => write!(f, "{}", err),
+            CsvError::Parse { line } => write!(f, "invalid asset on line {}", line),
+            CsvError::Reload(err) => write!(f, "{}", err)
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl std::error::Error for CsvError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReloadError {
+    /// The captures skip or repeat an idx, `found` being the idx
+    /// present where `expected` should have been.
+    NonContiguous { expected: usize, found: usize }
+}
+
+impl std::fmt::Display for ReloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReloadError::NonContiguous { expected, found } => 
+                write!(f, "expected capture with idx {}, found idx {}", expected, found)
+        }
+    }
+}
+
+impl std::error::Error for ReloadError {}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MutatorBaseCapture {
+    total_change: Decimal,
+    idx: usize
+}
+
+impl MutatorBaseCapture {
+    fn capture(base: &MutatorBase) -> MutatorBaseCapture {
+        MutatorBaseCapture { total_change: base.total_change.get(), idx: base.idx }
+    }
+
+    pub fn total_change(&self) -> Decimal {
+        self.total_change
+    }
+
+    pub fn idx(&self) -> usize {
+        self.idx
+    }
+}
+
+#[derive(Debug)]
+pub struct MutatorBase {
+    /// A human-readable label for diagnostics, such as `"Monthly Salary"`.
+    pub name: Option<String>,
+    pub idx: usize,
+    pub target_idx: usize,
+    pub change: Decimal,
+    /// The sum of the changes recorded by the mutator's events.
+    pub total_change: Cell<Decimal>,
+    pub is_add: bool,
+    pub cycle: u32,
+    pub unix_reference: u64,
+    /// Shifts the first event to `unix_reference + offset`.
+    pub offset: u64,
+    /// Whether the mutator creates events. Defaults to true.
+    pub active: Cell<bool>
+}
+
+impl MutatorBase {
+    pub fn new(idx: usize, target_idx: usize, change: Decimal, 
+        total_change: Decimal, is_add: bool, cycle: u32, unix_reference: u64) 
+            -> MutatorBase 
+    {
+        MutatorBase::with_offset(idx, target_idx, change, total_change, is_add, cycle, unix_reference, 0)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_offset(idx: usize, target_idx: usize, change: Decimal, 
+        total_change: Decimal, is_add: bool, cycle: u32, unix_reference: u64, offset: u64) 
+            -> MutatorBase 
+    {
+        MutatorBase { 
+            name: None, 
+            idx, 
+            target_idx, 
+            change, 
+            total_change: Cell::new(total_change), 
+            is_add, 
+            cycle, 
+            unix_reference, 
+            offset, 
+            active: Cell::new(true) 
+        }
+    }
+
+    pub fn builder() -> MutatorBaseBuilder {
+        MutatorBaseBuilder::new()
+    }
+
+    /// Adds `change` to the `total_change` recorded by this base.
+    pub fn record(&self, change: Decimal) {
+        self.total_change.set(self.total_change.get() + change)
+    }
+
+    /// Returns the number of events from `first_event` up to and
+    /// including `window_end`, or 0 if `first_event` is after `window_end`.
+    /// `cycle` must not be 0.
+    pub fn projection_length(&self, first_event: u64, window_end: u64) -> u64 {
+        if first_event > window_end {
+            return 0;
+        }
+
+        (window_end - first_event) / self.cycle as u64 + 1
+    }
+
+    /// Returns the first time `>= start` in the sequence
+    /// `unix_reference + offset + k * cycle`, saturating at `u64::MAX`
+    /// if there is no such time.
+    /// 
+    /// **Warning:** panics if `cycle` is 0 and the first event is before `start`.
+    pub fn unix_initial_event(&self, start: u64) -> u64 {
+        let first = self.unix_reference.saturating_add(self.offset);
+
+        if first >= start {
+            return first;
+        }
+
+        let cycle64 = self.cycle as u64;
+        let phase = (start - first) % cycle64;
+
+        if phase == 0 { start } else { start.saturating_add(cycle64 - phase) }
+    }
+
+    /// Returns the number of events `create_events` creates
+    /// within `[start, end]`, without creating them.
+    pub fn event_count(&self, start: u64, end: u64) -> u64 {
+        if self.cycle == 0 || !self.active.get() {
+            return 0;
+        }
+
+        self.projection_length(self.unix_initial_event(start), end)
+    }
+
+    /// Creates an event for every cycle landing within `[start, end]`,
+    /// targeting the mutator at `idx` of the `MutatorPool`.
+    /// An inactive mutator, or one with a `cycle` of 0, creates no events.
+    pub fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        if self.cycle == 0 || !self.active.get() {
+            return Vec::new();
+        }
+
+        let rie = self.unix_initial_event(start);
+        let cycle64 = self.cycle as u64;
+
+        (0..self.projection_length(rie, end))
+            .map(|i| Event::new(rie + cycle64 * i, idx, self.target_idx))
+            .collect()
+    }
+}
+
+/// A calendar cadence for scheduling a mutator from a start date.
+/// Cycles, references and time_pos are in Unix seconds, so a `Modeller`
+/// with a `time_unit` other than 1 should not mix them with its own.
+#[cfg(feature = "chrono")]
+pub enum Schedule {
+    EveryNSeconds(u64),
+    Weekly,
+    Monthly,
+    Yearly
+}
+
+#[cfg(feature = "chrono")]
+impl Schedule {
+    /// Sets the `cycle` and `unix_reference` of `base` to match the schedule.
+    /// Returns false, leaving `base` unchanged, if the schedule has no fixed
+    /// cycle (`Monthly` and `Yearly`) or its cycle does not fit in a `u32`,
+    /// in which case `events` should be used instead.
+    pub fn lower_into(&self, base: &mut MutatorBase, start: chrono::DateTime<chrono::Utc>) -> bool {
+        let cycle = match self {
+            Schedule::EveryNSeconds(seconds) => *seconds,
+            Schedule::Weekly => 7 * 24 * 60 * 60,
+            Schedule::Monthly | Schedule::Yearly => return false
+        };
+
+        match (u32::try_from(cycle), u64::try_from(start.timestamp())) {
+            (Ok(cycle), Ok(unix_reference)) if cycle != 0 => {
+                base.cycle = cycle;
+                base.unix_reference = unix_reference;
+                true
+            },
+            _ => false
+        }
+    }
+
+    /// Creates an event for every occurrence of the schedule from `start`
+    /// up to and including `end`. Monthly and yearly occurrences are
+    /// counted from `start`, falling on the last day of shorter months.
+    pub fn events(&self, start: chrono::DateTime<chrono::Utc>, end: chrono::DateTime<chrono::Utc>, 
+        mutator_idx: usize, asset_idx: usize) -> Vec<Event> 
+    {
+        if let Schedule::EveryNSeconds(0) = self {
+            return Vec::new();
+        }
+
+        let occurrence = |k: u32| match self {
+            Schedule::EveryNSeconds(seconds) => i64::try_from(*seconds).ok()
+                .and_then(|seconds| seconds.checked_mul(k as i64))
+                .and_then(chrono::TimeDelta::try_seconds)
+                .and_then(|delta| start.checked_add_signed(delta)),
+            Schedule::Weekly => start.checked_add_signed(chrono::TimeDelta::weeks(k as i64)),
+            Schedule::Monthly => start.checked_add_months(chrono::Months::new(k)),
+            Schedule::Yearly => k.checked_mul(12)
+                .and_then(|months| start.checked_add_months(chrono::Months::new(months)))
+        };
+
+        (0..)
+            .map_while(|k| occurrence(k).filter(|time| *time <= end))
+            .filter_map(|time| u64::try_from(time.timestamp()).ok())
+            .map(|time_pos| Event::new(time_pos, mutator_idx, asset_idx))
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BuildError {
+    /// A `cycle` of 0 never repeats, so no events could be created.
+    ZeroCycle
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildError::ZeroCycle => write!(f, "cycle must be greater than 0")
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Builds a `MutatorBase` without relying on argument order.
+/// `total_change` starts at zero and `is_add` defaults to true.
+pub struct MutatorBaseBuilder {
+    name: Option<String>,
+    idx: usize,
+    target_idx: usize,
+    change: Decimal,
+    is_add: bool,
+    cycle: u32,
+    unix_reference: u64,
+    offset: u64
+}
+
+impl Default for MutatorBaseBuilder {
+    fn default() -> Self {
+        MutatorBaseBuilder { 
+            name: None,
+            idx: 0, 
+            target_idx: 0, 
+            change: Decimal::ZERO, 
+            is_add: true, 
+            cycle: 0, 
+            unix_reference: 0,
+            offset: 0
+        }
+    }
+}
+
+impl MutatorBaseBuilder {
+    pub fn new() -> MutatorBaseBuilder {
+        MutatorBaseBuilder::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn idx(mut self, idx: usize) -> Self {
+        self.idx = idx;
+        self
+    }
+
+    pub fn target_idx(mut self, target_idx: usize) -> Self {
+        self.target_idx = target_idx;
+        self
+    }
+
+    pub fn change(mut self, change: Decimal) -> Self {
+        self.change = change;
+        self
+    }
+
+    pub fn is_add(mut self, is_add: bool) -> Self {
+        self.is_add = is_add;
+        self
+    }
+
+    pub fn cycle(mut self, cycle: u32) -> Self {
+        self.cycle = cycle;
+        self
+    }
+
+    pub fn unix_reference(mut self, unix_reference: u64) -> Self {
+        self.unix_reference = unix_reference;
+        self
+    }
+
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns `BuildError::ZeroCycle` if `cycle` was never set to
+    /// a non-zero value.
+    pub fn build(self) -> Result<MutatorBase, BuildError> {
+        if self.cycle == 0 {
+            return Err(BuildError::ZeroCycle);
+        }
+
+        let mut base = MutatorBase::with_offset(self.idx, self.target_idx, self.change, Decimal::ZERO, 
+            self.is_add, self.cycle, self.unix_reference, self.offset);
+        base.name = self.name;
+
+        Ok(base)
+    }
+}
+
+/// Identifies the type of mutator that produced a `MutatorCapture`.
+/// Mutators defined outside of this crate use `Custom`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MutatorVariant {
+    Standard,
+    Percentage,
+    Compound,
+    Backward,
+    Conditional,
+    Transfer,
+    Scaled,
+    Range,
+    Step,
+    Set,
+    OneShot,
+    Withdrawal,
+    FixedSchedule,
+    Tiered,
+    Capped,
+    Accrual,
+    Composite,
+    RandomWalk,
+    Custom(String)
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MutatorCapture {
+    base: MutatorBaseCapture,
+    variant: MutatorVariant,
+    /// Any state of the variant not held by its `MutatorBase`.
+    data: String,
+    /// Captures of any mutators the variant is made up of.
+    children: Vec<MutatorCapture>
+}
+
+impl MutatorCapture {
+    /// Captures `base` for a mutator of the given variant, for use by
+    /// `Mutator::capture` implementations outside of this crate.
+    pub fn new(base: &MutatorBase, variant: MutatorVariant) -> MutatorCapture {
+        MutatorCapture::with_data(base, variant, String::new())
+    }
+
+    /// Like `new`, but also captures `data` holding whatever state
+    /// the variant keeps outside of its `MutatorBase`.
+    pub fn with_data(base: &MutatorBase, variant: MutatorVariant, data: String) -> MutatorCapture {
+        MutatorCapture { base: MutatorBaseCapture::capture(base), variant, data, children: Vec::new() }
+    }
+
+    pub fn data(&self) -> &str {
+        &self.data
+    }
+
+    pub fn children(&self) -> &[MutatorCapture] {
+        &self.children
+    }
+
+    pub fn base(&self) -> &MutatorBaseCapture {
+        &self.base
+    }
+
+    pub fn variant(&self) -> &MutatorVariant {
+        &self.variant
+    }
+}
+
+pub trait Mutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal;
+
+    /// Like `on_event`, but with access to the `AssetPool` the event
+    /// is triggered against, for mutators that depend on other assets.
+    /// Defaults to `on_event`, ignoring the pool.
+    fn on_event_ctx(&self, original_value: Decimal, _asset_pool: &AssetPool) -> Decimal {
+        self.on_event(original_value)
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event>;
+
+    /// Like `create_events`, but with access to the `AssetPool` as it
+    /// stands when the events are created, for schedules that depend on
+    /// asset values. Defaults to `create_events`, ignoring the pool.
+    fn create_events_ctx(&self, start: u64, end: u64, idx: usize, _asset_pool: &AssetPool) -> Vec<Event> {
+        self.create_events(start, end, idx)
+    }
+
+    /// Returns the number of events `create_events` creates within
+    /// `[start, end]`, without creating them. Defaults to counting the
+    /// schedule of the mutator's base, so a mutator with its own schedule
+    /// should override this along with `create_events`.
+    fn event_count(&self, start: u64, end: u64) -> u64 {
+        self.borrow_base().event_count(start, end)
+    }
+
+    /// Returns the time of the first event `create_events` would create
+    /// at or after `from`, or `None` if there are no more, so an
+    /// `EventStream` can follow the schedule lazily. Defaults to the
+    /// schedule of the mutator's base, so a mutator with its own schedule
+    /// should override this along with `create_events`.
+    fn next_event_from(&self, from: u64) -> Option<u64> {
+        let base = self.borrow_base();
+
+        if base.cycle == 0 { None } else { Some(base.unix_initial_event(from)) }
+    }
+
+    fn capture(&self) -> MutatorCapture;
+    fn reset(&mut self, capture: MutatorCapture);
+    fn borrow_base(&self) -> &MutatorBase;
+
+    /// Whether the mutator creates events. The `MutatorPool` creates no
+    /// events for an inactive mutator. Defaults to the `active` flag of
+    /// the mutator's base.
+    fn is_active(&self) -> bool {
+        self.borrow_base().active.get()
+    }
+
+    /// A human-readable label for diagnostics.
+    /// Defaults to the `name` of the mutator's base.
+    fn name(&self) -> Option<&str> {
+        self.borrow_base().name.as_deref()
+    }
+}
+
+/// Adds `change` to the asset on every event.
+pub struct StandardMutator(pub MutatorBase);
+
+impl Mutator for StandardMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        self.0.record(self.0.change);
+        original_value + self.0.change
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.0.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.0, MutatorVariant::Standard)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.0.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.0
+    }
+}
+
+fn round_to(value: Decimal, dp: Option<u32>) -> Decimal {
+    match dp {
+        Some(dp) => value.round_dp(dp),
+        None => value
+    }
+}
+
+/// Grows the asset by `change` as a rate on every event,
+/// e.g. a `change` of `0.05` grows the asset by 5%.
+/// 
+/// If `rounding` is `Some`, the result of each event is rounded to that
+/// many decimal places, as a bank rounding each period would.
+pub struct PercentageMutator {
+    pub base: MutatorBase,
+    pub rounding: Option<u32>
+}
+
+impl PercentageMutator {
+    pub fn new(base: MutatorBase) -> PercentageMutator {
+        PercentageMutator { base, rounding: None }
+    }
+
+    pub fn with_rounding(base: MutatorBase, rounding: Option<u32>) -> PercentageMutator {
+        PercentageMutator { base, rounding }
+    }
+}
+
+impl Mutator for PercentageMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        let new_value = round_to(original_value * (Decimal::ONE + self.base.change), self.rounding);
+        self.base.record(new_value - original_value);
+
+        new_value
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.base.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.base, MutatorVariant::Percentage)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Compounds the asset by `change` as a rate, then subtracts
+/// `payment`, on every event.
+/// 
+/// If `rounding` is `Some`, the result of each event is rounded
+/// to that many decimal places.
+pub struct CompoundMutator {
+    pub base: MutatorBase,
+    pub payment: Decimal,
+    pub rounding: Option<u32>
+}
+
+impl CompoundMutator {
+    pub fn new(base: MutatorBase, payment: Decimal) -> CompoundMutator {
+        CompoundMutator { base, payment, rounding: None }
+    }
+
+    pub fn with_rounding(base: MutatorBase, payment: Decimal, rounding: Option<u32>) -> CompoundMutator {
+        CompoundMutator { base, payment, rounding }
+    }
+}
+
+impl Mutator for CompoundMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        let new_value = round_to(original_value * (Decimal::ONE + self.base.change) - self.payment, self.rounding);
+        self.base.record(new_value - original_value);
+
+        new_value
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.base.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.base, MutatorVariant::Compound)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Adds `change` to the asset on every event, counting down to a fixed
+/// deadline at `unix_reference + offset`. The last event lands exactly on
+/// the deadline, with each earlier event one `cycle` before the next, and
+/// no events fall after it.
+/// 
+/// The schedule depends only on the deadline, so it is the same however a
+/// projection is divided into intervals.
+pub struct BackwardMutator(pub MutatorBase);
+
+impl BackwardMutator {
+    /// The time of the last event.
+    pub fn deadline(&self) -> u64 {
+        self.0.unix_reference.saturating_add(self.0.offset)
+    }
+
+    /// Returns the least and greatest `k` for which `deadline - k * cycle`
+    /// falls within `[start, end]`, or `None` if no event does.
+    fn steps_within(&self, start: u64, end: u64) -> Option<(u64, u64)> {
+        let deadline = self.deadline();
+
+        if self.0.cycle == 0 || end < start || start > deadline {
+            return None;
+        }
+
+        let cycle64 = self.0.cycle as u64;
+        let nearest = (deadline - end.min(deadline)).div_ceil(cycle64);
+        let farthest = (deadline - start) / cycle64;
+
+        if nearest <= farthest { Some((nearest, farthest)) } else { None }
+    }
+}
+
+impl Mutator for BackwardMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        self.0.record(self.0.change);
+        original_value + self.0.change
+    }
+
+    /// Walks back from the deadline one cycle at a time, keeping
+    /// only the events within `[start, end]`.
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        if !self.0.active.get() {
+            return Vec::new();
+        }
+
+        let deadline = self.deadline();
+        let cycle64 = self.0.cycle as u64;
+
+        match self.steps_within(start, end) {
+            Some((nearest, farthest)) => (nearest..=farthest)
+                .rev()
+                .map(|k| Event::new(deadline - cycle64 * k, idx, self.0.target_idx))
+                .collect(),
+            None => Vec::new()
+        }
+    }
+
+    fn next_event_from(&self, from: u64) -> Option<u64> {
+        let deadline = self.deadline();
+        let cycle64 = self.0.cycle as u64;
+
+        if cycle64 == 0 || from > deadline {
+            return None;
+        }
+
+        Some(deadline - (deadline - from) / cycle64 * cycle64)
+    }
+
+    fn event_count(&self, start: u64, end: u64) -> u64 {
+        match self.steps_within(start, end) {
+            Some((nearest, farthest)) if self.0.active.get() => farthest - nearest + 1,
+            _ => 0
+        }
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.0, MutatorVariant::Backward)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.0.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.0
+    }
+}
+
+/// Adds `change` to the asset on every event where `predicate` holds
+/// for the asset's value, leaving the asset unchanged otherwise.
+/// 
+/// The predicate cannot be captured, so a `ConditionalMutator` can only
+/// be reconstructed from a `MutatorVariant::Conditional` capture by supplying the
+/// predicate again.
+pub struct ConditionalMutator {
+    pub base: MutatorBase,
+    predicate: Box<dyn Fn(Decimal) -> bool>
+}
+
+impl ConditionalMutator {
+    pub fn new(base: MutatorBase, predicate: impl Fn(Decimal) -> bool + 'static) -> ConditionalMutator {
+        ConditionalMutator { base, predicate: Box::new(predicate) }
+    }
+}
+
+impl Mutator for ConditionalMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        if (self.predicate)(original_value) {
+            self.base.record(self.base.change);
+            original_value + self.base.change
+        } else { original_value }
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.base.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.base, MutatorVariant::Conditional)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Moves `change` as a rate of the asset at `source_idx` into the
+/// target asset on every event, e.g. a `change` of `0.1` moves 10%
+/// of the source. The source is debited when the event is triggered.
+/// 
+/// Only the amount actually debited under the `AssetPool`'s `MutationPolicy`
+/// and scale is credited, so a `ClampAtZero` source empties out rather than
+/// going negative. Both legs are checked against the `MutationLimits` before
+/// the source is debited; if either would be rejected, or the credit would be
+/// altered by the policy, nothing is transferred.
+/// 
+/// Without access to the `AssetPool`, `on_event` leaves the target unchanged.
+pub struct TransferMutator {
+    pub base: MutatorBase,
+    pub source_idx: usize
+}
+
+impl TransferMutator {
+    pub fn new(base: MutatorBase, source_idx: usize) -> TransferMutator {
+        TransferMutator { base, source_idx }
+    }
+}
+
+impl Mutator for TransferMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        original_value
+    }
+
+    fn on_event_ctx(&self, original_value: Decimal, asset_pool: &AssetPool) -> Decimal {
+        let legs = asset_pool.get_raw(self.source_idx).and_then(|source| {
+            let debited = asset_pool.settle_change(source, -(source * self.base.change)).ok()?;
+            let credited = original_value + source - debited;
+            let accepted = asset_pool.settle_change(original_value, credited - original_value);
+
+            if accepted == Ok(credited) { Some((debited, credited)) } else { None }
+        });
+
+        if let Some((debited, credited)) = legs {
+            asset_pool.replace(AssetId(self.source_idx), debited);
+            self.base.record(credited - original_value);
+            credited
+        } else { original_value }
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.base.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.base, MutatorVariant::Transfer)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Adds `change` multiplied by the number of events so far on every
+/// event, so the asset grows by `change`, then `2 * change`, and so on.
+/// 
+/// The event count is captured, so resuming from an `EventMemento`
+/// continues the progression from where the memento was recorded
+/// rather than applying earlier steps again.
+pub struct ScaledMutator {
+    pub base: MutatorBase,
+    count: Cell<u64>
+}
+
+impl ScaledMutator {
+    pub fn new(base: MutatorBase) -> ScaledMutator {
+        ScaledMutator { base, count: Cell::new(0) }
+    }
+
+    /// The number of events applied so far.
+    pub fn count(&self) -> u64 {
+        self.count.get()
+    }
+}
+
+impl Mutator for ScaledMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        self.count.set(self.count.get() + 1);
+
+        let change = self.base.change * Decimal::from(self.count.get());
+        self.base.record(change);
+
+        original_value + change
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.base.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::with_data(&self.base, MutatorVariant::Scaled, self.count.get().to_string())
+    }
+
+    /// A capture without a valid count restarts the progression.
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+        self.count.set(capture.data.parse().unwrap_or(0));
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Deducts a progressive amount from the asset on every event, in the
+/// manner of tax brackets: each `(threshold, rate)` tier applies its rate
+/// to the portion of the value between its threshold and the next tier's.
+/// The base's `change` is unused.
+pub struct TieredMutator {
+    pub base: MutatorBase,
+    tiers: Vec<(Decimal, Decimal)>
+}
+
+impl TieredMutator {
+    /// `tiers` are sorted by threshold.
+    pub fn new(base: MutatorBase, mut tiers: Vec<(Decimal, Decimal)>) -> TieredMutator {
+        tiers.sort_by_key(|(threshold, _)| *threshold);
+
+        TieredMutator { base, tiers }
+    }
+
+    pub fn tiers(&self) -> &[(Decimal, Decimal)] {
+        &self.tiers
+    }
+
+    /// Returns the amount deducted from an asset of the given value.
+    pub fn tiered_amount(&self, value: Decimal) -> Decimal {
+        self.tiers
+            .iter()
+            .enumerate()
+            .fold(Decimal::ZERO, |accum, (i, (threshold, rate))| {
+                let upper = self.tiers.get(i + 1).map_or(value, |(next, _)| value.min(*next));
+
+                if upper > *threshold {
+                    accum + (upper - threshold) * rate
+                } else { accum }
+            })
+    }
+}
+
+impl Mutator for TieredMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        let amount = self.tiered_amount(original_value);
+        self.base.record(-amount);
+
+        original_value - amount
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.base.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.base, MutatorVariant::Tiered)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Adds `change` to the asset on every event until the base's
+/// `total_change` reaches `cap`, depositing only the remaining headroom
+/// on the event that reaches it and nothing after.
+/// 
+/// As the headroom is tracked by `total_change`, resuming from an
+/// `EventMemento` restores it.
+pub struct CappedMutator {
+    pub base: MutatorBase,
+    pub cap: Decimal
+}
+
+impl CappedMutator {
+    pub fn new(base: MutatorBase, cap: Decimal) -> CappedMutator {
+        CappedMutator { base, cap }
+    }
+
+    /// The amount that may still be deposited before reaching `cap`.
+    pub fn headroom(&self) -> Decimal {
+        (self.cap - self.base.total_change.get()).max(Decimal::ZERO)
+    }
+}
+
+impl Mutator for CappedMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        let deposit = self.base.change.min(self.headroom());
+        self.base.record(deposit);
+
+        original_value + deposit
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.base.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.base, MutatorVariant::Capped)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Accrues interest at a rate of `change` on the asset's value on every
+/// event, but only credits it to the asset on every `post_every`th event,
+/// such as accruing daily and posting every 30 days.
+/// 
+/// Until it is posted, the accrued interest is held as the base's
+/// `total_change`, which is zeroed on posting. Both it and the event count
+/// are captured, so resuming from an `EventMemento` keeps any pending interest.
+pub struct AccrualMutator {
+    pub base: MutatorBase,
+    pub post_every: u64,
+    count: Cell<u64>
+}
+
+impl AccrualMutator {
+    pub fn new(base: MutatorBase, post_every: u64) -> AccrualMutator {
+        AccrualMutator { base, post_every, count: Cell::new(0) }
+    }
+
+    /// The interest accrued but not yet posted.
+    pub fn pending(&self) -> Decimal {
+        self.base.total_change.get()
+    }
+}
+
+impl Mutator for AccrualMutator {
+    // `u64::is_multiple_of` needs Rust 1.87.
+    #[allow(clippy::manual_is_multiple_of)]
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        let count = self.count.get() + 1;
+        self.count.set(count);
+        self.base.record(original_value * self.base.change);
+
+        if count % self.post_every.max(1) == 0 {
+            original_value + self.base.total_change.replace(Decimal::ZERO)
+        } else { original_value }
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.base.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::with_data(&self.base, MutatorVariant::Accrual, self.count.get().to_string())
+    }
+
+    /// A capture without a valid count restarts the posting cycle.
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+        self.count.set(capture.data.parse().unwrap_or(0));
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Adds `change` to the asset on every event, then clamps the result
+/// to at least `floor` and at most `ceil`, either of which may be left unbounded.
+pub struct RangeMutator {
+    pub base: MutatorBase,
+    pub floor: Option<Decimal>,
+    pub ceil: Option<Decimal>
+}
+
+impl RangeMutator {
+    pub fn new(base: MutatorBase, floor: Option<Decimal>, ceil: Option<Decimal>) -> RangeMutator {
+        RangeMutator { base, floor, ceil }
+    }
+}
+
+impl Mutator for RangeMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        let mut value = original_value + self.base.change;
+
+        if let Some(floor) = self.floor {
+            value = value.max(floor);
+        }
+
+        if let Some(ceil) = self.ceil {
+            value = value.min(ceil);
+        }
+
+        self.base.record(value - original_value);
+        value
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.base.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.base, MutatorVariant::Range)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Adds `change` to the asset for the first `threshold` events,
+/// then `second_change` on every event after.
+/// 
+/// The event count is captured, so resuming from an `EventMemento`
+/// does not restart the first step.
+pub struct StepMutator {
+    pub base: MutatorBase,
+    pub second_change: Decimal,
+    pub threshold: u64,
+    count: Cell<u64>
+}
+
+impl StepMutator {
+    pub fn new(base: MutatorBase, second_change: Decimal, threshold: u64) -> StepMutator {
+        StepMutator { base, second_change, threshold, count: Cell::new(0) }
+    }
+
+    /// The number of events applied so far.
+    pub fn count(&self) -> u64 {
+        self.count.get()
+    }
+}
+
+impl Mutator for StepMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        let count = self.count.get();
+        self.count.set(count + 1);
+
+        let change = if count < self.threshold { self.base.change } else { self.second_change };
+        self.base.record(change);
+
+        original_value + change
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.base.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::with_data(&self.base, MutatorVariant::Step, self.count.get().to_string())
+    }
+
+    /// A capture without a valid count restarts from the first step.
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+        self.count.set(capture.data.parse().unwrap_or(0));
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Sets the asset to `change` on every event.
+/// 
+/// **Warning:** the asset's prior value is discarded, including the
+/// effect of any other mutator triggered on it at the same time_pos.
+pub struct SetMutator(pub MutatorBase);
+
+impl Mutator for SetMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        self.0.record(self.0.change - original_value);
+        self.0.change
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.0.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.0, MutatorVariant::Set)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.0.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.0
+    }
+}
+
+/// Withdraws `change` from the asset on every event, never taking it
+/// below zero. Once the asset is depleted, no further events are created.
+/// 
+/// Whether the asset has been depleted is captured, so resuming from
+/// an `EventMemento` restores it.
+pub struct WithdrawalMutator {
+    pub base: MutatorBase,
+    depleted: Cell<bool>
+}
+
+impl WithdrawalMutator {
+    pub fn new(base: MutatorBase) -> WithdrawalMutator {
+        WithdrawalMutator { base, depleted: Cell::new(false) }
+    }
+
+    /// Whether an event has left the asset at zero.
+    pub fn is_depleted(&self) -> bool {
+        self.depleted.get()
+    }
+}
+
+impl Mutator for WithdrawalMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        let new_value = (original_value - self.base.change).max(Decimal::ZERO);
+
+        if new_value.is_zero() {
+            self.depleted.set(true);
+        }
+
+        self.base.record(new_value - original_value);
+        new_value
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        if self.depleted.get() {
+            Vec::new()
+        } else { self.base.create_events(start, end, idx) }
+    }
+
+    fn create_events_ctx(&self, start: u64, end: u64, idx: usize, asset_pool: &AssetPool) -> Vec<Event> {
+        match asset_pool.get_raw(self.base.target_idx) {
+            Some(balance) if balance <= Decimal::ZERO => Vec::new(),
+            _ => self.create_events(start, end, idx)
+        }
+    }
+
+    /// Counts the events `create_events` creates, so it may overcount
+    /// when the balance runs out within `[start, end]`.
+    fn event_count(&self, start: u64, end: u64) -> u64 {
+        if self.depleted.get() { 0 } else { self.base.event_count(start, end) }
+    }
+
+    fn next_event_from(&self, from: u64) -> Option<u64> {
+        if self.depleted.get() || self.base.cycle == 0 {
+            None
+        } else { Some(self.base.unix_initial_event(from)) }
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::with_data(&self.base, MutatorVariant::Withdrawal, self.depleted.get().to_string())
+    }
+
+    /// A capture without a valid flag is treated as not depleted.
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+        self.depleted.set(capture.data.parse().unwrap_or(false));
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Adds `change` to the asset once, at `unix_time`, ignoring the
+/// schedule of its base.
+pub struct OneShotMutator {
+    pub base: MutatorBase,
+    pub unix_time: u64
+}
+
+impl OneShotMutator {
+    pub fn new(base: MutatorBase, unix_time: u64) -> OneShotMutator {
+        OneShotMutator { base, unix_time }
+    }
+}
+
+impl Mutator for OneShotMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        self.base.record(self.base.change);
+        original_value + self.base.change
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        if (start..=end).contains(&self.unix_time) {
+            vec![Event::new(self.unix_time, idx, self.base.target_idx)]
+        } else { Vec::new() }
+    }
+
+    fn event_count(&self, start: u64, end: u64) -> u64 {
+        (start..=end).contains(&self.unix_time) as u64
+    }
+
+    fn next_event_from(&self, from: u64) -> Option<u64> {
+        if self.unix_time >= from { Some(self.unix_time) } else { None }
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.base, MutatorVariant::OneShot)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Adds `change` to the asset at each of `times`, ignoring the
+/// schedule of its base. `times` need not be sorted.
+pub struct FixedScheduleMutator {
+    pub base: MutatorBase,
+    pub times: Vec<u64>
+}
+
+impl FixedScheduleMutator {
+    pub fn new(base: MutatorBase, times: Vec<u64>) -> FixedScheduleMutator {
+        FixedScheduleMutator { base, times }
+    }
+}
+
+impl Mutator for FixedScheduleMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        self.base.record(self.base.change);
+        original_value + self.base.change
+    }
+
+    /// Events are returned sorted by time_pos.
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        let mut times: Vec<u64> = self.times
+            .iter()
+            .copied()
+            .filter(|time| (start..=end).contains(time))
+            .collect();
+        times.sort_unstable();
+
+        times.into_iter()
+            .map(|time| Event::new(time, idx, self.base.target_idx))
+            .collect()
+    }
+
+    fn event_count(&self, start: u64, end: u64) -> u64 {
+        self.times
+            .iter()
+            .filter(|time| (start..=end).contains(*time))
+            .count() as u64
+    }
+
+    fn next_event_from(&self, from: u64) -> Option<u64> {
+        self.times
+            .iter()
+            .copied()
+            .filter(|time| *time >= from)
+            .min()
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::new(&self.base, MutatorVariant::FixedSchedule)
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Applies each of its children to the asset in order on every event,
+/// following the schedule of the first child.
+pub struct CompositeMutator(Vec<Box<dyn Mutator>>);
+
+impl CompositeMutator {
+    /// Returns `None` if `children` is empty.
+    pub fn new(children: Vec<Box<dyn Mutator>>) -> Option<CompositeMutator> {
+        if children.is_empty() {
+            None
+        } else { Some(CompositeMutator(children)) }
+    }
+
+    pub fn children(&self) -> &[Box<dyn Mutator>] {
+        &self.0
+    }
+}
+
+impl Mutator for CompositeMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        self.0
+            .iter()
+            .fold(original_value, |value, child| child.on_event(value))
+    }
+
+    fn on_event_ctx(&self, original_value: Decimal, asset_pool: &AssetPool) -> Decimal {
+        self.0
+            .iter()
+            .fold(original_value, |value, child| child.on_event_ctx(value, asset_pool))
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.0[0].create_events(start, end, idx)
+    }
+
+    fn create_events_ctx(&self, start: u64, end: u64, idx: usize, asset_pool: &AssetPool) -> Vec<Event> {
+        self.0[0].create_events_ctx(start, end, idx, asset_pool)
+    }
+
+    fn event_count(&self, start: u64, end: u64) -> u64 {
+        self.0[0].event_count(start, end)
+    }
+
+    fn next_event_from(&self, from: u64) -> Option<u64> {
+        self.0[0].next_event_from(from)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        let mut capture = MutatorCapture::new(self.borrow_base(), MutatorVariant::Composite);
+        capture.children = self.0.iter().map(|child| child.capture()).collect();
+
+        capture
+    }
+
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.0
+            .iter_mut()
+            .zip(capture.children)
+            .for_each(|(child, capture)| child.reset(capture));
+    }
+
+    /// Borrows the base of the first child.
+    fn borrow_base(&self) -> &MutatorBase {
+        self.0[0].borrow_base()
+    }
+}
+
+/// Grows the asset by a rate drawn from a normal distribution with
+/// the given `mean` and `stddev` on every event.
+/// 
+/// The seed and number of draws are captured, so resetting replays the
+/// generator to the same position and a resumed projection reproduces
+/// exactly the same draws.
+#[cfg(feature = "rand")]
+pub struct RandomWalkMutator {
+    pub base: MutatorBase,
+    pub mean: f64,
+    pub stddev: f64,
+    seed: u64,
+    draws: Cell<u64>,
+    rng: RefCell<rand::rngs::StdRng>
+}
+
+#[cfg(feature = "rand")]
+impl RandomWalkMutator {
+    pub fn new(base: MutatorBase, mean: f64, stddev: f64, seed: u64) -> RandomWalkMutator {
+        use rand::SeedableRng;
+
+        RandomWalkMutator { 
+            base, 
+            mean, 
+            stddev, 
+            seed, 
+            draws: Cell::new(0), 
+            rng: RefCell::new(rand::rngs::StdRng::seed_from_u64(seed)) 
+        }
+    }
+
+    /// Draws from a standard normal distribution via the Box-Muller transform.
+    fn sample(&self) -> f64 {
+        use rand::Rng;
+
+        let mut rng = self.rng.borrow_mut();
+        let u1: f64 = 1.0 - rng.gen::<f64>();
+        let u2: f64 = rng.gen();
+        self.draws.set(self.draws.get() + 1);
+
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+
+    fn rewind(&self, draws: u64) {
+        use rand::SeedableRng;
+
+        self.rng.replace(rand::rngs::StdRng::seed_from_u64(self.seed));
+        self.draws.set(0);
+
+        for _ in 0..draws {
+            self.sample();
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Mutator for RandomWalkMutator {
+    fn on_event(&self, original_value: Decimal) -> Decimal {
+        let rate = self.mean + self.stddev * self.sample();
+        let new_value = original_value * (Decimal::ONE + Decimal::try_from(rate).unwrap_or(Decimal::ZERO));
+        self.base.record(new_value - original_value);
+
+        new_value
+    }
+
+    fn create_events(&self, start: u64, end: u64, idx: usize) -> Vec<Event> {
+        self.base.create_events(start, end, idx)
+    }
+
+    fn capture(&self) -> MutatorCapture {
+        MutatorCapture::with_data(&self.base, MutatorVariant::RandomWalk, 
+            format!("{},{}", self.seed, self.draws.get()))
+    }
+
+    /// A capture without a valid seed and draw count restarts the
+    /// generator from this mutator's own seed.
+    fn reset(&mut self, capture: MutatorCapture) {
+        self.base.total_change.set(capture.base.total_change);
+
+        let state = capture.data.split_once(',')
+            .and_then(|(seed, draws)| Some((seed.parse().ok()?, draws.parse().ok()?)));
+
+        let draws = match state {
+            Some((seed, draws)) => {
+                self.seed = seed;
+                draws
+            },
+            None => 0
+        };
+
+        self.rewind(draws);
+    }
+
+    fn borrow_base(&self) -> &MutatorBase {
+        &self.base
+    }
+}
+
+/// Holds the mutators of a model, addressed by idx. As with `AssetPool`,
+/// `MutatorPool::default()` creates a bare, empty `MutatorPool`.
+#[derive(Default)]
+pub struct MutatorPool {
+    mutators: RefCell<Vec<Box<dyn Mutator>>>
+}
+
+impl MutatorPool {
+    pub fn new() -> Rc<MutatorPool> {
+        Rc::new(MutatorPool { mutators: RefCell::new(Vec::new()) })
+    }
+
+    /// Creates a `MutatorPool` able to load `capacity` mutators without reallocating.
+    pub fn with_capacity(capacity: usize) -> Rc<MutatorPool> {
+        Rc::new(MutatorPool { mutators: RefCell::new(Vec::with_capacity(capacity)) })
+    }
+
+    /// Wraps a bare `MutatorPool`, such as one from `MutatorPool::default()`,
+    /// in an `Rc`, as `new` returns it.
+    pub fn into_rc(self) -> Rc<MutatorPool> {
+        Rc::new(self)
+    }
+
+    /// Reserves capacity for at least `additional` more mutators.
+    pub fn reserve(&self, additional: usize) {
+        self.mutators.borrow_mut().reserve(additional)
+    }
+
+    pub fn load(&self, mutator: Box<dyn Mutator>) -> MutatorId {
+        let mut mutators = self.mutators.borrow_mut();
+        mutators.push(mutator);
+
+        MutatorId(mutators.len() - 1)
+    }
+
+    /// Removes and returns the mutator at `idx`, shifting every mutator
+    /// after it down by one, or returns `None` if there is no such mutator.
+    /// 
+    /// **Warning:** every idx greater than `idx` is invalidated, so any
+    /// `Event::mutator_idx` referencing one must be patched, using the table
+    /// returned by `remove_and_remap`. The `MutatorBase::idx` of each shifted
+    /// mutator is left as it was.
+    pub fn remove(&self, idx: usize) -> Option<Box<dyn Mutator>> {
+        self.remove_and_remap(idx).map(|(mutator, _)| mutator)
+    }
+
+    /// As `remove`, but also returns a remapping table of
+    /// `(old_idx, new_idx)` for each shifted mutator.
+    #[allow(clippy::type_complexity)]
+    pub fn remove_and_remap(&self, idx: usize) -> Option<(Box<dyn Mutator>, Vec<(usize, usize)>)> {
+        let mut mutators = self.mutators.borrow_mut();
+
+        if idx < mutators.len() {
+            let mutator = mutators.remove(idx);
+            let remap = (idx..mutators.len())
+                .map(|new_idx| (new_idx + 1, new_idx))
+                .collect();
+
+            Some((mutator, remap))
+        } else { None }
+    }
+
+    pub fn len(&self) -> usize {
+        self.mutators.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mutators.borrow().is_empty()
+    }
+
+    /// Returns the base of the mutator at `idx`, or `None` if there is
+    /// no mutator at `idx`. The `MutatorPool` stays borrowed until the
+    /// returned `Ref` is dropped, so it must not be loaded into until then.
+    pub fn base_at(&self, idx: usize) -> Option<Ref<'_, MutatorBase>> {
+        Ref::filter_map(self.mutators.borrow(), |mutators| {
+            mutators.get(idx).map(|mutator| mutator.borrow_base())
+        }).ok()
+    }
+
+    /// Returns the value produced by the mutator for the given
+    /// original value, or `None` if there is no such mutator.
+    pub fn on_event(&self, id: MutatorId, original_value: Decimal) -> Option<Decimal> {
+        self.on_event_raw(id.0, original_value)
+    }
+
+    /// As `on_event`, but for the mutator at the raw `idx`.
+    pub fn on_event_raw(&self, idx: usize, original_value: Decimal) -> Option<Decimal> {
+        self.mutators.borrow()
+            .get(idx)
+            .map(|mutator| mutator.on_event(original_value))
+    }
+
+    /// # Safety
+    /// 
+    /// `idx` must be in bounds of the `MutatorPool`.
+    pub unsafe fn on_event_unchecked(&self, idx: usize, original_value: Decimal) -> Decimal {
+        self.mutators.borrow()
+            .get_unchecked(idx)
+            .on_event(original_value)
+    }
+
+    /// Returns the value produced by the mutator for the given
+    /// original value with access to `asset_pool`, or `None` if there
+    /// is no such mutator.
+    pub fn on_event_ctx(&self, id: MutatorId, original_value: Decimal, asset_pool: &AssetPool) -> Option<Decimal> {
+        self.on_event_ctx_raw(id.0, original_value, asset_pool)
+    }
+
+    /// As `on_event_ctx`, but for the mutator at the raw `idx`.
+    pub fn on_event_ctx_raw(&self, idx: usize, original_value: Decimal, asset_pool: &AssetPool) -> Option<Decimal> {
+        self.mutators.borrow()
+            .get(idx)
+            .map(|mutator| mutator.on_event_ctx(original_value, asset_pool))
+    }
+
+    /// # Safety
+    /// 
+    /// `idx` must be in bounds of the `MutatorPool`.
+    pub unsafe fn on_event_ctx_unchecked(&self, idx: usize, original_value: Decimal, asset_pool: &AssetPool) -> Decimal {
+        self.mutators.borrow()
+            .get_unchecked(idx)
+            .on_event_ctx(original_value, asset_pool)
+    }
+
+    /// Returns the `total_change` of the mutator at `idx`,
+    /// or `None` if there is no mutator at `idx`.
+    pub fn total_change(&self, idx: usize) -> Option<Decimal> {
+        self.mutators.borrow()
+            .get(idx)
+            .map(|mutator| mutator.borrow_base().total_change.get())
+    }
+
+    /// Activates or deactivates the mutator at `idx`, leaving every idx
+    /// in place. Returns false if there is no mutator at `idx`.
+    pub fn set_active(&self, idx: usize, active: bool) -> bool {
+        if let Some(mutator) = self.mutators.borrow().get(idx) {
+            mutator.borrow_base().active.set(active);
+            true
+        } else { false }
+    }
+
+    /// Returns the idx of every mutator targeting the asset at `asset_idx`,
+    /// in ascending order.
+    pub fn mutators_for(&self, asset_idx: usize) -> Vec<usize> {
+        self.mutators.borrow()
+            .iter()
+            .enumerate()
+            .filter(|(_, mutator)| mutator.borrow_base().target_idx == asset_idx)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Creates the events of every loaded mutator within the inclusive
+    /// window `[start, end]`, each tagged with its mutator's idx.
+    /// The events are grouped by mutator and are not sorted.
+    pub fn create_all_events(&self, start: u64, end: u64) -> Vec<Event> {
+        self.mutators.borrow()
+            .iter()
+            .enumerate()
+            .filter(|(_, mutator)| mutator.is_active())
+            .flat_map(|(idx, mutator)| mutator.create_events(start, end, idx))
+            .collect()
+    }
+
+    /// Like `create_all_events`, but creates each mutator's events
+    /// with access to `asset_pool`.
+    pub fn create_all_events_ctx(&self, start: u64, end: u64, asset_pool: &AssetPool) -> Vec<Event> {
+        self.mutators.borrow()
+            .iter()
+            .enumerate()
+            .filter(|(_, mutator)| mutator.is_active())
+            .flat_map(|(idx, mutator)| mutator.create_events_ctx(start, end, idx, asset_pool))
+            .collect()
+    }
+
+    fn capture(&self) -> Vec<MutatorCapture> {
+        self.mutators.borrow()
+            .iter()
+            .map(|mutator| mutator.capture())
+            .collect()
+    }
+
+    /// Resets each mutator with the capture at the same position.
+    fn reset(&self, captures: Vec<MutatorCapture>) {
+        self.mutators.borrow_mut()
+            .iter_mut()
+            .zip(captures)
+            .for_each(|(mutator, capture)| mutator.reset(capture));
+    }
+}
+
+/// Events compare equal by time_pos, mutator_idx and asset_idx alone,
+/// regardless of whether they have been applied.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Event {
+    time_pos: u64,
+    mutator_idx: usize,
+    asset_idx: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    applied: Cell<bool>
+}
+
+/// Creates `n` events evenly spaced across `[start, end]`, the first at
+/// `start` and, if there is more than one, the last at `end`. A single
+/// event lands at `start`, and an empty window creates no events.
+pub fn spread_events(start: u64, end: u64, n: usize, mutator_idx: usize, asset_idx: usize) -> Vec<Event> {
+    if end < start {
+        return Vec::new();
+    }
+
+    let span = (end - start) as u128;
+    let gaps = n.saturating_sub(1).max(1) as u128;
+
+    (0..n)
+        .map(|i| Event::new(start + (span * i as u128 / gaps) as u64, mutator_idx, asset_idx))
+        .collect()
+}
+
+impl std::fmt::Debug for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Event")
+            .field("time_pos", &self.time_pos)
+            .field("mutator_idx", &self.mutator_idx)
+            .field("asset_idx", &self.asset_idx)
+            .field("applied", &self.applied.get())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Event@{} mut#{} -> asset#{}", self.time_pos, self.mutator_idx, self.asset_idx)
+    }
+}
+
+/// Events are ordered by time_pos, with ties broken by mutator_idx
+/// and then asset_idx, so events at the same time always trigger in
+/// the same order.
+impl Ord for Event {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.time_pos, self.mutator_idx, self.asset_idx)
+            .cmp(&(other.time_pos, other.mutator_idx, other.asset_idx))
+    }
+}
+
+impl PartialEq for Event {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Event {}
+
+impl PartialOrd for Event {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Event {
+    pub fn new(time_pos: u64, mutator_idx: usize, asset_idx: usize) -> Event {
+        Event { time_pos, mutator_idx, asset_idx, applied: Cell::new(false) }
+    }
+
+    /// Like `new`, but returns an error identifying whichever of the asset
+    /// or the mutator is not in its pool.
+    pub fn new_checked(time_pos: u64, mutator_idx: usize, asset_idx: usize, 
+        asset_pool: &AssetPool, mutator_pool: &MutatorPool) -> Result<Event, EventError> 
+    {
+        if asset_idx >= asset_pool.len() {
+            return Err(EventError::AssetNotFound(asset_idx));
+        }
+
+        if mutator_idx >= mutator_pool.len() {
+            return Err(EventError::MutatorNotFound(mutator_idx));
+        }
+
+        Ok(Event::new(time_pos, mutator_idx, asset_idx))
+    }
+
+    /// Returns a copy of the event `delta` time units later,
+    /// saturating at `u64::MAX`. The copy has not been applied.
+    pub fn shifted(&self, delta: u64) -> Event {
+        Event::new(self.time_pos.saturating_add(delta), self.mutator_idx, self.asset_idx)
+    }
+
+    /// Whether `trigger` has applied this event.
+    pub fn is_applied(&self) -> bool {
+        self.applied.get()
+    }
+
+    /// Allows `trigger` to apply this event again.
+    pub fn reset_applied(&self) {
+        self.applied.set(false)
+    }
+
+    /// Replaces the value of the event's asset with the value produced
+    /// by the event's mutator.
+    /// Returns an error identifying whichever of the asset or the mutator
+    /// could not be found, or the asset if the `AssetPool`'s `MutationPolicy`
+    /// rejected the new value, leaving the asset unchanged.
+    /// 
+    /// Once applied, triggering the same event again does nothing until
+    /// `reset_applied` is called. A clone starts out applied only if the
+    /// original was. An event that returns an error is not marked as applied.
+    pub fn trigger(&self, asset_pool: &AssetPool, mutator_pool: &MutatorPool) -> Result<(), TriggerError> {
+        if self.applied.get() {
+            return Ok(());
+        }
+
+        let ov = asset_pool.get_raw(self.asset_idx)
+            .ok_or(TriggerError::AssetNotFound(self.asset_idx))?;
+        let nv = mutator_pool.on_event_ctx_raw(self.mutator_idx, ov, asset_pool)
+            .ok_or(TriggerError::MutatorNotFound(self.mutator_idx))?;
+
+        if !asset_pool.mutate_raw(self.asset_idx, nv - ov) {
+            return Err(TriggerError::MutationRejected(self.asset_idx));
+        }
+
+        self.applied.set(true);
+        Ok(())
+    }
+
+    /// Unlike `trigger`, this neither checks nor sets whether the
+    /// event has been applied.
+    /// 
+    /// # Safety
+    /// 
+    /// The event's `asset_idx` and `mutator_idx` must be in bounds of
+    /// the given `AssetPool` and `MutatorPool` respectively.
+    pub unsafe fn trigger_unchecked(&self, asset_pool: &AssetPool, mutator_pool: &MutatorPool) {
+        let ov = asset_pool.get_unchecked(self.asset_idx);
+        let nv = mutator_pool.on_event_ctx_unchecked(self.mutator_idx, ov, asset_pool);
+
+        asset_pool.mutate_unchecked(self.asset_idx, nv - ov)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TriggerError {
+    AssetNotFound(usize),
+    MutatorNotFound(usize),
+    MutationRejected(usize)
+}
+
+impl std::fmt::Display for TriggerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TriggerError::AssetNotFound(idx) => write!(f, "no asset with idx {}", idx),
+            TriggerError::MutatorNotFound(idx) => write!(f, "no mutator with idx {}", idx),
+            TriggerError::MutationRejected(idx) => write!(f, "mutation of asset {} was rejected", idx)
+        }
+    }
+}
+
+impl std::error::Error for TriggerError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum EventError {
+    AssetNotFound(usize),
+    MutatorNotFound(usize)
+}
+
+impl std::fmt::Display for EventError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventError::AssetNotFound(idx) => write!(f, "no asset with idx {}", idx),
+            EventError::MutatorNotFound(idx) => write!(f, "no mutator with idx {}", idx)
+        }
+    }
+}
+
+impl std::error::Error for EventError {}
+
+/// Yields the events of every mutator in a `MutatorPool` within the
+/// inclusive window `[start, end]` in sorted order, holding only the
+/// next event time of each mutator at a time.
+/// 
+/// Each mutator's schedule is followed through `next_event_from`, and the
+/// events at each time are created by its `create_events`, so the stream
+/// yields the same events as `MutatorPool::create_all_events`.
+/// The `MutatorPool` is borrowed only while each event is created,
+/// but must not be loaded into or removed from while streaming.
+pub struct EventStream<'a> {
+    mutator_pool: &'a MutatorPool,
+    end: u64,
+    pending: BinaryHeap<Reverse<(u64, usize)>>,
+    ready: VecDeque<Event>
+}
+
+impl<'a> EventStream<'a> {
+    pub fn new(mutator_pool: &'a MutatorPool, start: u64, end: u64) -> EventStream<'a> {
+        let pending = mutator_pool.mutators.borrow()
+            .iter()
+            .enumerate()
+            .filter(|(_, mutator)| mutator.is_active())
+            .filter_map(|(idx, mutator)| mutator.next_event_from(start).map(|time_pos| (time_pos, idx)))
+            .filter(|(time_pos, _)| *time_pos <= end)
+            .map(Reverse)
+            .collect();
+
+        EventStream { mutator_pool, end, pending, ready: VecDeque::new() }
+    }
+}
+
+impl Iterator for EventStream<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        while self.ready.is_empty() {
+            let Reverse((time_pos, idx)) = self.pending.pop()?;
+            let mutators = self.mutator_pool.mutators.borrow();
+
+            let mut events = mutators[idx].create_events(time_pos, time_pos, idx);
+            events.sort();
+            self.ready.extend(events);
+
+            let next_pos = time_pos.checked_add(1).and_then(|from| mutators[idx].next_event_from(from));
+            if let Some(next_pos) = next_pos.filter(|next_pos| *next_pos <= self.end) {
+                self.pending.push(Reverse((next_pos, idx)));
+            }
+        }
+
+        self.ready.pop_front()
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventMemento {
+    time_pos: u64,
+    asset_captures: Vec<AssetCapture>,
+    mutator_states: Vec<MutatorCapture>
+}
+
+impl EventMemento {
+    pub fn time_pos(&self) -> u64 {
+        self.time_pos
+    }
+
+    pub fn asset_captures(&self) -> &[AssetCapture] {
+        &self.asset_captures
+    }
+
+    pub fn mutator_states(&self) -> &[MutatorCapture] {
+        &self.mutator_states
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntervalPoint {
-    account_captures: Vec<AccountCapture>,
-    mutator_captures: Vec<MutatorBaseCapture>,
-    asset_captures: Vec<AssetCapture>
-}
\ No newline at end of file
+    time_pos: u64,
+    asset_captures: Vec<AssetCapture>,
+    mutator_captures: Vec<MutatorCapture>
+}
+
+impl IntervalPoint {
+    pub fn time_pos(&self) -> u64 {
+        self.time_pos
+    }
+
+    pub fn asset_captures(&self) -> &[AssetCapture] {
+        &self.asset_captures
+    }
+
+    pub fn mutator_captures(&self) -> &[MutatorCapture] {
+        &self.mutator_captures
+    }
+}
+
+/// The value of an asset before and after a single event was triggered,
+/// as passed to the observer of `Modeller::project_with_observer`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventLog {
+    time_pos: u64,
+    mutator_idx: usize,
+    asset_idx: usize,
+    before: Decimal,
+    after: Decimal
+}
+
+impl EventLog {
+    pub fn time_pos(&self) -> u64 {
+        self.time_pos
+    }
+
+    pub fn mutator_idx(&self) -> usize {
+        self.mutator_idx
+    }
+
+    pub fn asset_idx(&self) -> usize {
+        self.asset_idx
+    }
+
+    pub fn before(&self) -> Decimal {
+        self.before
+    }
+
+    pub fn after(&self) -> Decimal {
+        self.after
+    }
+}
+
+/// Counts and timings gathered while running a projection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectionMetrics {
+    events_generated: usize,
+    events_triggered: usize,
+    peak_events: usize,
+    intervals: usize,
+    elapsed: Duration
+}
+
+impl ProjectionMetrics {
+    /// The number of events created across all intervals.
+    pub fn events_generated(&self) -> usize {
+        self.events_generated
+    }
+
+    /// The number of events successfully triggered.
+    pub fn events_triggered(&self) -> usize {
+        self.events_triggered
+    }
+
+    /// The most events created for a single interval.
+    pub fn peak_events(&self) -> usize {
+        self.peak_events
+    }
+
+    /// The number of intervals run, excluding any skipped by a memento.
+    pub fn intervals(&self) -> usize {
+        self.intervals
+    }
+
+    /// The time taken by the projection.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResultPacket {
+    interval_points: Vec<IntervalPoint>,
+    event_mementos: Vec<EventMemento>,
+    metrics: ProjectionMetrics
+}
+
+impl ResultPacket {
+    pub fn metrics(&self) -> &ProjectionMetrics {
+        &self.metrics
+    }
+
+    pub fn interval_points(&self) -> &[IntervalPoint] {
+        &self.interval_points
+    }
+
+    pub fn event_mementos(&self) -> &[EventMemento] {
+        &self.event_mementos
+    }
+
+    /// Takes the recorded `EventMemento`s, e.g. to resume a projection
+    /// from one of them.
+    pub fn into_event_mementos(self) -> Vec<EventMemento> {
+        self.event_mementos
+    }
+
+    /// Returns the value of the asset at `asset_idx` at the end of the
+    /// given interval, or `None` if either is out of bounds.
+    pub fn asset_value_at(&self, interval: usize, asset_idx: usize) -> Option<Decimal> {
+        self.interval_points
+            .get(interval)?
+            .asset_captures
+            .get(asset_idx)
+            .map(|cap| cap.value)
+    }
+
+    /// Returns the value of the asset at `asset_idx` at each recorded
+    /// time_pos, in time order. Values from an `EventMemento` are taken
+    /// before the events at its time_pos, and values from an `IntervalPoint`
+    /// after every event up to the end of its interval.
+    /// 
+    /// The series only has a point per event if the projection was run
+    /// with `Modeller::record_every_event` set.
+    pub fn timeseries(&self, asset_idx: usize) -> Vec<(u64, Decimal)> {
+        let mementos = self.event_mementos
+            .iter()
+            .filter_map(|memento| memento.asset_captures
+                .get(asset_idx)
+                .map(|cap| (memento.time_pos, false, cap.value)));
+
+        let points = self.interval_points
+            .iter()
+            .filter_map(|point| point.asset_captures
+                .get(asset_idx)
+                .map(|cap| (point.time_pos, true, cap.value)));
+
+        let mut out: Vec<(u64, bool, Decimal)> = mementos.chain(points).collect();
+        out.sort_by_key(|(time_pos, is_point, _)| (*time_pos, *is_point));
+
+        out.into_iter()
+            .map(|(time_pos, _, value)| (time_pos, value))
+            .collect()
+    }
+
+    /// Returns the compound annual growth rate of the asset at `asset_idx`,
+    /// `(end / start)^(1 / years) - 1`, between the first and last values
+    /// of its `timeseries`, taking `units_per_year` time units as a year.
+    /// 
+    /// Returns `None` if fewer than two distinct times were recorded, or if
+    /// the first value is not positive or the last value is negative.
+    /// 
+    /// **Warning:** as `Decimal` has no fractional power, the rate is
+    /// computed with `f64`, so it is only accurate to around 15 significant digits.
+    pub fn cagr(&self, asset_idx: usize, units_per_year: u64) -> Option<Decimal> {
+        use rust_decimal::prelude::ToPrimitive;
+
+        let series = self.timeseries(asset_idx);
+        let (start_time, start_value) = series.first()?;
+        let (end_time, end_value) = series.last()?;
+
+        if end_time <= start_time || units_per_year == 0 
+            || start_value <= &Decimal::ZERO || end_value < &Decimal::ZERO 
+        {
+            return None;
+        }
+
+        let years = (end_time - start_time) as f64 / units_per_year as f64;
+        let ratio = (end_value / start_value).to_f64()?;
+
+        Decimal::try_from(ratio.powf(years.recip()) - 1.0).ok()
+    }
+
+    /// Returns the value of every asset at the end of the last interval,
+    /// in idx order. Empty if no intervals were recorded.
+    pub fn final_values(&self) -> Vec<Decimal> {
+        self.interval_points
+            .last()
+            .map(|point| point.asset_captures.iter().map(|cap| cap.value).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the end time of each interval, alongside a matrix with a row
+    /// per interval holding the value of each asset in idx order.
+    /// 
+    /// Returns the first interval with a different number of assets
+    /// to the first interval, if any.
+    pub fn to_table(&self) -> Result<(Vec<u64>, Vec<Vec<Decimal>>), usize> {
+        let asset_count = self.interval_points.first().map_or(0, |point| point.asset_captures.len());
+
+        if let Some(interval) = self.interval_points
+            .iter()
+            .position(|point| point.asset_captures.len() != asset_count) 
+        {
+            return Err(interval);
+        }
+
+        let times = self.interval_points.iter().map(|point| point.time_pos).collect();
+        let rows = self.interval_points
+            .iter()
+            .map(|point| point.asset_captures.iter().map(|cap| cap.value).collect())
+            .collect();
+
+        Ok((times, rows))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProjectionError {
+    NoAssets,
+    NoMutators,
+    InvalidInterval { interval_len: u64 },
+    /// The idx of every mutator whose `target_idx` has no asset.
+    InvalidTargets(Vec<usize>),
+    /// The projection would trigger more than `limit` events.
+    EventLimitExceeded { generated: usize, limit: usize },
+    Trigger(TriggerError)
+}
+
+impl From<TriggerError> for ProjectionError {
+    fn from(err: TriggerError) -> Self {
+        ProjectionError::Trigger(err)
+    }
+}
+
+impl std::fmt::Display for ProjectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectionError::NoAssets => write!(f, "the asset pool is empty"),
+            ProjectionError::NoMutators => write!(f, "the mutator pool is empty"),
+            ProjectionError::InvalidInterval { interval_len } => 
+                write!(f, "invalid interval length {}", interval_len),
+            ProjectionError::InvalidTargets(idxs) => 
+                write!(f, "mutators {:?} target assets that do not exist", idxs),
+            ProjectionError::EventLimitExceeded { generated, limit } => 
+                write!(f, "projection generated {} events, over the limit of {}", generated, limit),
+            ProjectionError::Trigger(err) => write!(f, "failed to trigger event: {}", err)
+        }
+    }
+}
+
+impl std::error::Error for ProjectionError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RestoreError {
+    Reload(ReloadError),
+    /// The factory built no mutator for the capture at this idx.
+    MissingMutator(usize)
+}
+
+impl From<ReloadError> for RestoreError {
+    fn from(err: ReloadError) -> Self {
+        RestoreError::Reload(err)
+    }
+}
+
+impl std::fmt::Display for RestoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestoreError::Reload(err) => write!(f, "failed to reload assets: {}", err),
+            RestoreError::MissingMutator(idx) => write!(f, "no mutator was built for capture {}", idx)
+        }
+    }
+}
+
+impl std::error::Error for RestoreError {}
+
+/// The state of a `Modeller`'s assets, mutators and events,
+/// from which an equivalent `Modeller` can be restored.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModellerSnapshot {
+    asset_captures: Vec<AssetCapture>,
+    mutator_captures: Vec<MutatorCapture>,
+    events: Vec<Event>
+}
+
+impl ModellerSnapshot {
+    pub fn asset_captures(&self) -> &[AssetCapture] {
+        &self.asset_captures
+    }
+
+    pub fn mutator_captures(&self) -> &[MutatorCapture] {
+        &self.mutator_captures
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+}
+
+pub struct Modeller {
+    asset_pool: Rc<AssetPool>,
+    mutator_pool: Rc<MutatorPool>,
+    /// Events triggered alongside the events created by the `MutatorPool`.
+    /// Each projection triggers a fresh copy, so an event is triggered even
+    /// if it has already been applied.
+    pub events: Vec<Event>,
+    /// Whether `project` records an `EventMemento` at every distinct
+    /// time_pos an event fires at, rather than only at the first
+    /// event of each interval. Defaults to false.
+    pub record_every_event: bool,
+    /// The most mementos kept by `checkpoint` before the oldest is evicted.
+    /// Defaults to 0, which disables the history.
+    pub history_capacity: usize,
+    /// The most events a projection may trigger. A projection over the
+    /// limit fails before any event is created or triggered.
+    /// Defaults to `None`, which allows any number of events.
+    /// 
+    /// **Warning:** events are counted by each mutator's `event_count`, so
+    /// a custom mutator that overrides `create_events` with a different
+    /// schedule must override `event_count` to match.
+    pub max_events: Option<usize>,
+    /// A tolerance at which a projection stops early: once no asset's value
+    /// changes by more than it over an interval, no further intervals run.
+    /// Defaults to `None`, which always runs every interval.
+    pub stop_when_stable: Option<Decimal>,
+    /// The number of seconds in one time unit, as used by `to_seconds` and
+    /// `from_seconds`. A projection does not read it: time_pos, cycles,
+    /// references and offsets are plain integers, so a model counting in
+    /// hours converts with a `time_unit` of 3600 before projecting.
+    /// A value of 0 is treated as 1. Defaults to 1.
+    pub time_unit: u64,
+    history: VecDeque<EventMemento>
+}
+
+impl Modeller {
+    pub fn new(asset_pool: Rc<AssetPool>, mutator_pool: Rc<MutatorPool>) -> Modeller {
+        Modeller { 
+            asset_pool, 
+            mutator_pool, 
+            events: Vec::new(), 
+            record_every_event: false, 
+            history_capacity: 0, 
+            max_events: None, 
+            stop_when_stable: None, 
+            time_unit: 1, 
+            history: VecDeque::new() 
+        }
+    }
+
+    /// Creates a `Modeller` triggering `events` alongside those created
+    /// by the `MutatorPool`, sorting them as kept by `add_event`.
+    pub fn with_events(asset_pool: Rc<AssetPool>, mutator_pool: Rc<MutatorPool>, 
+        mut events: Vec<Event>) -> Modeller 
+    {
+        events.sort();
+
+        Modeller { events, ..Modeller::new(asset_pool, mutator_pool) }
+    }
+
+    /// Converts a number of time units to seconds, saturating at `u64::MAX`.
+    pub fn to_seconds(&self, units: u64) -> u64 {
+        units.saturating_mul(self.time_unit.max(1))
+    }
+
+    /// Converts seconds to a whole number of time units, rounding down.
+    pub fn from_seconds(&self, seconds: u64) -> u64 {
+        seconds / self.time_unit.max(1)
+    }
+
+    /// Captures the current state of the assets and mutators onto the
+    /// history, evicting the oldest memento if it is at `history_capacity`.
+    /// The memento's time_pos is 0, as the `Modeller` only tracks time
+    /// within a projection.
+    pub fn checkpoint(&mut self) {
+        if self.history_capacity == 0 {
+            return;
+        }
+
+        while self.history.len() >= self.history_capacity {
+            self.history.pop_front();
+        }
+
+        let memento = self.memento(0);
+        self.history.push_back(memento);
+    }
+
+    /// Restores the assets and mutators to the most recent checkpoint,
+    /// removing it from the history. Returns false if the history is empty.
+    pub fn rewind(&mut self) -> bool {
+        if let Some(memento) = self.history.pop_back() {
+            self.restore_memento(memento);
+            true
+        } else { false }
+    }
+
+    /// The retained checkpoints, oldest first.
+    pub fn history(&self) -> &VecDeque<EventMemento> {
+        &self.history
+    }
+
+    /// Inserts `event` into `events`, keeping them sorted.
+    /// An event is inserted after any existing events equal to it.
+    pub fn add_event(&mut self, event: Event) {
+        let pos = self.events.partition_point(|other| *other <= event);
+        self.events.insert(pos, event);
+    }
+
+    /// Removes and returns the first event at `time_pos` for the mutator
+    /// at `mutator_idx`, or `None` if there is no such event.
+    /// `events` must be sorted by time_pos, as kept by `add_event`.
+    pub fn remove_event(&mut self, time_pos: u64, mutator_idx: usize) -> Option<Event> {
+        let start = self.events.partition_point(|event| event.time_pos < time_pos);
+
+        let pos = self.events[start..]
+            .iter()
+            .take_while(|event| event.time_pos == time_pos)
+            .position(|event| event.mutator_idx == mutator_idx)?;
+
+        Some(self.events.remove(start + pos))
+    }
+
+    pub fn clear_events(&mut self) {
+        self.events.clear();
+    }
+
+    /// Captures the assets, mutators and events of the `Modeller`.
+    pub fn snapshot(&self) -> ModellerSnapshot {
+        ModellerSnapshot { 
+            asset_captures: self.asset_pool.capture(), 
+            mutator_captures: self.mutator_pool.capture(), 
+            events: self.events.clone() 
+        }
+    }
+
+    /// Creates a `Modeller` from a snapshot. As mutators cannot be rebuilt
+    /// from their captures alone, `mutator_factory` is called with each
+    /// capture in idx order to build a mutator of the captured `MutatorVariant`,
+    /// which is then reset to the capture.
+    pub fn restore<F>(snapshot: ModellerSnapshot, mut mutator_factory: F) -> Result<Modeller, RestoreError> 
+        where F: FnMut(&MutatorCapture) -> Option<Box<dyn Mutator>>
+    {
+        let asset_pool = AssetPool::reload(snapshot.asset_captures)?;
+        let mutator_pool = MutatorPool::with_capacity(snapshot.mutator_captures.len());
+
+        for (idx, capture) in snapshot.mutator_captures.into_iter().enumerate() {
+            let mut mutator = mutator_factory(&capture).ok_or(RestoreError::MissingMutator(idx))?;
+            mutator.reset(capture);
+            mutator_pool.load(mutator);
+        }
+
+        Ok(Modeller::with_events(asset_pool, mutator_pool, snapshot.events))
+    }
+
+    /// Checks that every mutator targets an asset in the `AssetPool`,
+    /// returning the idx of each mutator that does not.
+    pub fn validate(&self) -> Result<(), Vec<usize>> {
+        let asset_count = self.asset_pool.len();
+
+        let invalid: Vec<usize> = self.mutator_pool.mutators.borrow()
+            .iter()
+            .enumerate()
+            .filter(|(_, mutator)| mutator.borrow_base().target_idx >= asset_count)
+            .map(|(idx, _)| idx)
+            .collect();
+
+        if invalid.is_empty() { Ok(()) } else { Err(invalid) }
+    }
+
+    /// Runs `interval_count` intervals of `interval_len` time units each,
+    /// with each interval starting `interval_delay` time units after the
+    /// end of the last, so interval `k` covers
+    /// `[start + k * (interval_len + interval_delay), start + k * (interval_len + interval_delay) + interval_len)`.
+    /// Time still advances during the delay between intervals, but no events
+    /// fire within it. An event landing on the end of an interval belongs
+    /// to whatever follows it.
+    /// 
+    /// An `IntervalPoint` is recorded at the end of each interval, and an
+    /// `EventMemento` is recorded before the first event of each interval.
+    /// The events of each interval are created by `create_events_ctx`
+    /// from the assets as they stand at the start of the interval.
+    /// 
+    /// If a `memento` is given, assets and mutators are restored to its
+    /// captures and every event before its `time_pos` is skipped, resuming
+    /// the projection from the point the memento was recorded at.
+    pub fn project(&self, start: u64, interval_len: u64, interval_count: u32, 
+        interval_delay: u64, memento: Option<EventMemento>) 
+            -> Result<ResultPacket, ProjectionError> 
+    {
+        let windows = self.interval_windows(start, interval_len, interval_count, interval_delay)?;
+
+        self.run_windows(&windows, memento, None, None)
+    }
+
+    /// Runs the same projection as `project`, passing an `EventLog` to
+    /// `observer` after each event is triggered, in the order they trigger.
+    /// Events before the `time_pos` of a given `memento` are not logged.
+    #[allow(clippy::too_many_arguments)]
+    pub fn project_with_observer<F: FnMut(EventLog)>(&self, start: u64, interval_len: u64, 
+        interval_count: u32, interval_delay: u64, memento: Option<EventMemento>, mut observer: F) 
+            -> Result<ResultPacket, ProjectionError> 
+    {
+        let windows = self.interval_windows(start, interval_len, interval_count, interval_delay)?;
+
+        self.run_windows(&windows, memento, Some(&mut observer), None)
+    }
+
+    /// Runs the same projection as `project`, also returning the lowest
+    /// and highest value each asset reached as `(min, max)` in idx order,
+    /// including its value before the first event and after every event,
+    /// not just at the ends of intervals. An asset is tracked however its
+    /// value changes, including as the source of a `TransferMutator`.
+    /// 
+    /// If a `memento` is given, tracking starts from its captured values.
+    pub fn project_with_extrema(&self, start: u64, interval_len: u64, interval_count: u32, 
+        interval_delay: u64, memento: Option<EventMemento>) 
+            -> Result<(ResultPacket, Vec<(Decimal, Decimal)>), ProjectionError> 
+    {
+        let windows = self.interval_windows(start, interval_len, interval_count, interval_delay)?;
+        let mut extrema = Vec::new();
+
+        let result = self.run_windows(&windows, memento, None, Some(&mut extrema))?;
+
+        Ok((result, extrema))
+    }
+
+    fn interval_windows(&self, start: u64, interval_len: u64, interval_count: u32, 
+        interval_delay: u64) -> Result<Vec<(u64, u64)>, ProjectionError> 
+    {
+        self.check_pools()?;
+
+        if interval_len == 0 {
+            return Err(ProjectionError::InvalidInterval { interval_len });
+        }
+
+        Ok((0..interval_count as u64)
+            .map(|interval| Modeller::interval_bounds(start, interval_len, interval_delay, interval))
+            .collect())
+    }
+
+    /// Runs each of the `[start, end)` periods in turn, as `project` runs
+    /// its intervals, recording an `IntervalPoint` at the end of each.
+    /// Periods may differ in length, and should be given in ascending
+    /// order without overlapping.
+    pub fn project_periods(&self, periods: &[(u64, u64)], memento: Option<EventMemento>) 
+        -> Result<ResultPacket, ProjectionError> 
+    {
+        self.check_pools()?;
+
+        if let Some((start, end)) = periods.iter().find(|(start, end)| end <= start) {
+            return Err(ProjectionError::InvalidInterval { interval_len: end.saturating_sub(*start) });
+        }
+
+        let windows: Vec<(u64, u64)> = periods.iter()
+            .map(|(start, end)| (*start, end - 1))
+            .collect();
+
+        self.run_windows(&windows, memento, None, None)
+    }
+
+    /// Triggers every event within `[start, start + length)`, then returns
+    /// the value of each asset in idx order. Unlike `project`, no captures
+    /// or mementos are recorded.
+    pub fn project_final(&self, start: u64, length: u64) -> Result<Vec<Decimal>, ProjectionError> {
+        self.check_pools()?;
+
+        if length == 0 {
+            return Err(ProjectionError::InvalidInterval { interval_len: length });
+        }
+
+        let window = Modeller::interval_bounds(start, length, 0, 0);
+        self.check_event_limit(&[window], start)?;
+
+        let mut events = self.events_within(window.0, window.1);
+        events.sort();
+
+        for event in &events {
+            event.trigger(&self.asset_pool, &self.mutator_pool)?;
+        }
+
+        Ok(self.asset_pool.iter_values().collect())
+    }
+
+    /// Searches for the initial value of the asset at `asset_idx` for which
+    /// `project_final(start, length)` leaves it within `tolerance` of
+    /// `target`, by bisection. Both pools are left as they were found.
+    /// 
+    /// **Warning:** the final value is assumed to be monotonic in the
+    /// initial value, as with any mix of additive, percentage and compound
+    /// mutators. Otherwise the search may settle on any solution, or return
+    /// `None` when one exists.
+    /// 
+    /// Returns `None` if there is no such asset, a projection fails, or no
+    /// initial value within the range of `Decimal` reaches the target.
+    pub fn solve_initial(&self, asset_idx: usize, target: Decimal, start: u64, length: u64, 
+        tolerance: Decimal) -> Option<Decimal> 
+    {
+        self.asset_pool.get_raw(asset_idx)?;
+
+        let final_value = |initial: Decimal| {
+            let memento = self.memento(start);
+            self.asset_pool.replace(AssetId(asset_idx), initial);
+
+            let value = self.project_final(start, length)
+                .ok()
+                .and_then(|values| values.get(asset_idx).copied());
+
+            self.restore_memento(memento);
+            value
+        };
+
+        let at_zero = final_value(Decimal::ZERO)?;
+        let increasing = final_value(Decimal::ONE)? >= at_zero;
+        let upward = (target >= at_zero) == increasing;
+
+        // Widen the bracket away from zero until it contains the target.
+        let (mut lo, mut hi) = if upward { (Decimal::ZERO, Decimal::ONE) } else { (Decimal::NEGATIVE_ONE, Decimal::ZERO) };
+
+        loop {
+            let edge = if upward { hi } else { lo };
+            let value = final_value(edge)?;
+
+            if (value - target).abs() <= tolerance {
+                return Some(edge);
+            }
+
+            if (value >= target) == (increasing == upward) {
+                break;
+            }
+
+            if upward {
+                lo = hi;
+                hi = hi.checked_mul(Decimal::TWO)?;
+            } else {
+                hi = lo;
+                lo = lo.checked_mul(Decimal::TWO)?;
+            }
+        }
+
+        while lo < hi {
+            let mid = (lo + hi) / Decimal::TWO;
+            let value = final_value(mid)?;
+
+            if (value - target).abs() <= tolerance {
+                return Some(mid);
+            }
+
+            if mid == lo || mid == hi {
+                return None;
+            }
+
+            if (value < target) == increasing { lo = mid } else { hi = mid }
+        }
+
+        None
+    }
+
+    fn check_pools(&self) -> Result<(), ProjectionError> {
+        if self.asset_pool.is_empty() {
+            return Err(ProjectionError::NoAssets);
+        }
+
+        if self.mutator_pool.is_empty() {
+            return Err(ProjectionError::NoMutators);
+        }
+
+        self.validate().map_err(ProjectionError::InvalidTargets)
+    }
+
+    /// Checks that the events of every window from `resume_pos` on
+    /// fit within `max_events`.
+    fn check_event_limit(&self, windows: &[(u64, u64)], resume_pos: u64) -> Result<(), ProjectionError> {
+        if let Some(limit) = self.max_events {
+            let mut generated = 0;
+
+            for &(interval_start, interval_end) in windows {
+                if interval_end >= resume_pos {
+                    generated += self.event_count(interval_start.max(resume_pos), interval_end);
+                }
+
+                if generated > limit {
+                    return Err(ProjectionError::EventLimitExceeded { generated, limit });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Triggers the events of each inclusive window in turn.
+    /// If `extrema` is given, it is filled with the `(min, max)` of every
+    /// asset from the point the projection starts, updated after each event.
+    fn run_windows(&self, windows: &[(u64, u64)], memento: Option<EventMemento>, 
+        mut observer: Option<&mut dyn FnMut(EventLog)>, mut extrema: Option<&mut Vec<(Decimal, Decimal)>>) 
+            -> Result<ResultPacket, ProjectionError> 
+    {
+        let started = Instant::now();
+        let mut result = ResultPacket { 
+            interval_points: Vec::new(), 
+            event_mementos: Vec::new(), 
+            metrics: ProjectionMetrics::default() 
+        };
+        let mut resume_pos = windows.first().map_or(0, |(start, _)| *start);
+
+        if let Some(memento) = &memento {
+            resume_pos = resume_pos.max(memento.time_pos);
+        }
+
+        self.check_event_limit(windows, resume_pos)?;
+
+        if let Some(memento) = memento {
+            self.restore_memento(memento);
+        }
+
+        if let Some(extrema) = extrema.as_mut() {
+            **extrema = self.asset_pool.iter_values().map(|value| (value, value)).collect();
+        }
+
+        let mut previous: Vec<Decimal> = if self.stop_when_stable.is_some() {
+            self.asset_pool.iter_values().collect()
+        } else { Vec::new() };
+
+        for &(interval_start, interval_end) in windows {
+            if interval_end < resume_pos {
+                continue;
+            }
+
+            let mut events = self.events_within(interval_start.max(resume_pos), interval_end);
+            events.sort();
+
+            result.metrics.intervals += 1;
+            result.metrics.events_generated += events.len();
+            result.metrics.peak_events = result.metrics.peak_events.max(events.len());
+
+            for (i, event) in events.iter().enumerate() {
+                let first_at_time = i == 0 || events[i - 1].time_pos != event.time_pos;
+
+                if first_at_time && (i == 0 || self.record_every_event) {
+                    result.event_mementos.push(self.memento(event.time_pos));
+                }
+
+                if let Some(observer) = observer.as_mut() {
+                    let before = self.asset_pool.get_raw(event.asset_idx);
+
+                    event.trigger(&self.asset_pool, &self.mutator_pool)?;
+                    observer(EventLog { 
+                        time_pos: event.time_pos, 
+                        mutator_idx: event.mutator_idx, 
+                        asset_idx: event.asset_idx, 
+                        before: before.unwrap_or_default(), 
+                        after: self.asset_pool.get_raw(event.asset_idx).unwrap_or_default() 
+                    });
+                } else {
+                    event.trigger(&self.asset_pool, &self.mutator_pool)?;
+                }
+
+                // An event may change assets other than its own, such as
+                // the source of a transfer, so every asset is checked.
+                if let Some(extrema) = extrema.as_mut() {
+                    extrema.iter_mut()
+                        .zip(self.asset_pool.iter_values())
+                        .for_each(|((min, max), value)| {
+                            *min = (*min).min(value);
+                            *max = (*max).max(value);
+                        });
+                }
+
+                result.metrics.events_triggered += 1;
+            }
+
+            let asset_captures = self.asset_pool.capture();
+            let stable = self.stop_when_stable.is_some_and(|tolerance| {
+                asset_captures
+                    .iter()
+                    .zip(&previous)
+                    .all(|(cap, value)| (cap.value - value).abs() < tolerance)
+            });
+
+            if self.stop_when_stable.is_some() {
+                previous = asset_captures.iter().map(|cap| cap.value).collect();
+            }
+
+            result.interval_points.push(IntervalPoint { 
+                time_pos: interval_end, 
+                asset_captures, 
+                mutator_captures: self.mutator_pool.capture() 
+            });
+
+            if stable {
+                break;
+            }
+        }
+
+        result.metrics.elapsed = started.elapsed();
+        Ok(result)
+    }
+
+    /// Collects the events `project` would trigger over the same intervals,
+    /// sorted by time_pos, without triggering any of them.
+    pub fn preview(&self, start: u64, interval_len: u64, interval_count: u32, 
+        interval_delay: u64) -> Vec<Event> 
+    {
+        if interval_len == 0 {
+            return Vec::new();
+        }
+
+        let mut out: Vec<Event> = (0..interval_count as u64)
+            .flat_map(|interval| {
+                let (interval_start, interval_end) = 
+                    Modeller::interval_bounds(start, interval_len, interval_delay, interval);
+
+                self.events_within(interval_start, interval_end)
+            })
+            .collect();
+
+        out.sort();
+        out
+    }
+
+    /// Returns the inclusive bounds of the given interval.
+    /// `interval_len` must not be 0.
+    fn interval_bounds(start: u64, interval_len: u64, interval_delay: u64, interval: u64) -> (u64, u64) {
+        let interval_start = interval_len
+            .saturating_add(interval_delay)
+            .saturating_mul(interval)
+            .saturating_add(start);
+
+        (interval_start, interval_start.saturating_add(interval_len - 1))
+    }
+
+    /// Runs the same projection as `project` on several threads, splitting
+    /// the assets into parts that are each projected on their own copy of
+    /// the pools, then merging the `IntervalPoint`s of every part.
+    /// 
+    /// Assets within the same entry of `groups` are always projected
+    /// together, and any other asset may be projected apart from the rest.
+    /// Each mutator is projected with the asset it targets, so a mutator
+    /// that depends on or changes another asset, such as a `TransferMutator`
+    /// and its source, must have both assets in the same group.
+    /// 
+    /// As the loaded mutators cannot be sent between threads, each thread
+    /// builds its own by calling `factory` with every mutator idx. The
+    /// mutator built must match the one loaded at that idx, and is reset
+    /// to the loaded mutator's capture before any event is triggered.
+    /// Whether the loaded mutator is active is kept.
+    /// 
+    /// Like `project`, the pools are left as they are after the last
+    /// interval. Unlike `project`, no `EventMemento`s are recorded, and
+    /// `stop_when_stable` is ignored.
+    #[cfg(feature = "rayon")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn project_parallel<F>(&self, start: u64, interval_len: u64, interval_count: u32, 
+        interval_delay: u64, groups: &[Vec<usize>], factory: F) 
+            -> Result<ResultPacket, ProjectionError> 
+    where 
+        F: Fn(usize) -> Box<dyn Mutator> + Sync
+    {
+        self.project_in_parts(start, interval_len, interval_count, interval_delay, 
+            groups, factory, rayon::current_num_threads())
+    }
+
+    /// Runs `project_parallel`, splitting the assets into at most `parts` parts.
+    #[cfg(feature = "rayon")]
+    #[allow(clippy::too_many_arguments)]
+    fn project_in_parts<F>(&self, start: u64, interval_len: u64, interval_count: u32, 
+        interval_delay: u64, groups: &[Vec<usize>], factory: F, parts: usize) 
+            -> Result<ResultPacket, ProjectionError> 
+    where 
+        F: Fn(usize) -> Box<dyn Mutator> + Sync
+    {
+        use rayon::prelude::*;
+
+        let started = Instant::now();
+        let windows = self.interval_windows(start, interval_len, interval_count, interval_delay)?;
+
+        self.check_event_limit(&windows, start)?;
+
+        let asset_count = self.asset_pool.len();
+        let parts = parts.clamp(1, asset_count);
+        let asset_parts = Modeller::partition_assets(asset_count, groups, parts);
+        let mutator_parts: Vec<usize> = self.mutator_pool.mutators.borrow()
+            .iter()
+            .map(|mutator| asset_parts.get(mutator.borrow_base().target_idx).copied().unwrap_or(0))
+            .collect();
+
+        let values: Vec<Decimal> = self.asset_pool.iter_values().collect();
+        let (policy, scale, limits) = (self.asset_pool.policy(), self.asset_pool.scale(), self.asset_pool.limits());
+        let mutator_states = self.mutator_pool.capture();
+        let active: Vec<bool> = self.mutator_pool.mutators.borrow()
+            .iter()
+            .map(|mutator| mutator.is_active())
+            .collect();
+        let events: Vec<(u64, usize, usize)> = self.events
+            .iter()
+            .map(|event| (event.time_pos, event.mutator_idx, event.asset_idx))
+            .collect();
+
+        let runs = (0..parts)
+            .into_par_iter()
+            .map(|part| {
+                let asset_pool = AssetPool::with_policy(policy);
+                let mutator_pool = MutatorPool::new();
+
+                asset_pool.set_scale(scale);
+                asset_pool.set_limits(limits);
+
+                for value in &values {
+                    asset_pool.load(Asset::new(*value));
+                }
+
+                for (idx, state) in mutator_states.iter().enumerate() {
+                    let mut mutator = factory(idx);
+                    mutator.reset(state.clone());
+                    mutator_pool.load(mutator);
+                }
+
+                // An event for an asset that is not in the pool is left to
+                // the first part, so it fails just as it would in `project`.
+                let events = events.iter()
+                    .filter(|(_, _, asset_idx)| asset_parts.get(*asset_idx).copied().unwrap_or(0) == part)
+                    .map(|&(time_pos, mutator_idx, asset_idx)| Event::new(time_pos, mutator_idx, asset_idx))
+                    .collect();
+                let modeller = Modeller { events, ..Modeller::new(asset_pool, mutator_pool) };
+
+                windows.iter()
+                    .map(|&(interval_start, interval_end)| {
+                        let mut events = modeller.part_events_within(interval_start, interval_end, |idx| {
+                            active[idx] && mutator_parts[idx] == part
+                        });
+                        events.sort_unstable();
+
+                        for event in &events {
+                            event.trigger(&modeller.asset_pool, &modeller.mutator_pool)?;
+                        }
+
+                        Ok((events.len(), modeller.asset_pool.capture(), modeller.mutator_pool.capture()))
+                    })
+                    .collect::<Result<Vec<_>, ProjectionError>>()
+            })
+            .collect::<Result<Vec<_>, ProjectionError>>()?;
+
+        let mut result = ResultPacket { 
+            interval_points: Vec::with_capacity(windows.len()), 
+            event_mementos: Vec::new(), 
+            metrics: ProjectionMetrics::default() 
+        };
+        let mut runs: Vec<_> = runs.into_iter().map(Vec::into_iter).collect();
+
+        for &(_, interval_end) in &windows {
+            let mut events = 0;
+            let mut asset_captures = Vec::with_capacity(parts);
+            let mut mutator_captures = Vec::with_capacity(parts);
+
+            for (count, assets, mutators) in runs.iter_mut().filter_map(Iterator::next) {
+                events += count;
+                asset_captures.push(assets);
+                mutator_captures.push(mutators);
+            }
+
+            result.metrics.intervals += 1;
+            result.metrics.events_generated += events;
+            result.metrics.events_triggered += events;
+            result.metrics.peak_events = result.metrics.peak_events.max(events);
+
+            result.interval_points.push(IntervalPoint { 
+                time_pos: interval_end, 
+                asset_captures: Modeller::pick_owned(asset_captures, &asset_parts), 
+                mutator_captures: Modeller::pick_owned(mutator_captures, &mutator_parts) 
+            });
+        }
+
+        if let Some(point) = result.interval_points.last() {
+            self.asset_pool.assets.borrow()
+                .iter()
+                .zip(&point.asset_captures)
+                .for_each(|(asset, cap)| asset.value.set(cap.value));
+
+            self.mutator_pool.mutators.borrow_mut()
+                .iter_mut()
+                .zip(&point.mutator_captures)
+                .for_each(|(mutator, cap)| mutator.reset(cap.clone()));
+        }
+
+        result.metrics.elapsed = started.elapsed();
+        Ok(result)
+    }
+
+    /// Splits `asset_count` assets into at most `parts` parts of whole
+    /// groups, merging any groups that overlap, and returns the part of
+    /// each asset in idx order. Groups are assigned in order of their
+    /// lowest idx, moving on to the next part once one holds its share.
+    #[cfg(feature = "rayon")]
+    fn partition_assets(asset_count: usize, groups: &[Vec<usize>], parts: usize) -> Vec<usize> {
+        fn find(roots: &mut [usize], mut idx: usize) -> usize {
+            while roots[idx] != idx {
+                roots[idx] = roots[roots[idx]];
+                idx = roots[idx];
+            }
+
+            idx
+        }
+
+        let mut roots: Vec<usize> = (0..asset_count).collect();
+
+        for group in groups {
+            let mut members = group.iter().copied().filter(|idx| *idx < asset_count);
+
+            if let Some(first) = members.next() {
+                for idx in members {
+                    let (first_root, root) = (find(&mut roots, first), find(&mut roots, idx));
+                    roots[root] = first_root;
+                }
+            }
+        }
+
+        let share = asset_count.div_ceil(parts.max(1));
+        let mut root_parts = std::collections::HashMap::new();
+        let mut sizes = vec![0; parts.max(1)];
+        let mut part = 0;
+
+        (0..asset_count)
+            .map(|idx| {
+                let root = find(&mut roots, idx);
+                let owner = *root_parts.entry(root).or_insert_with(|| {
+                    if sizes[part] >= share && part + 1 < sizes.len() {
+                        part += 1;
+                    }
+
+                    part
+                });
+
+                sizes[owner] += 1;
+                owner
+            })
+            .collect()
+    }
+
+    /// Takes each item from the run of the part that owns its position.
+    #[cfg(feature = "rayon")]
+    fn pick_owned<T>(runs: Vec<Vec<T>>, owners: &[usize]) -> Vec<T> {
+        let mut runs: Vec<_> = runs.into_iter().map(Vec::into_iter).collect();
+
+        owners.iter()
+            .filter_map(|owner| {
+                let mut owned = None;
+
+                for (part, items) in runs.iter_mut().enumerate() {
+                    let item = items.next();
+
+                    if part == *owner {
+                        owned = item;
+                    }
+                }
+
+                owned
+            })
+            .collect()
+    }
+
+    /// As `events_within`, but only creates the events of the mutators
+    /// for which `owned` returns true.
+    #[cfg(feature = "rayon")]
+    fn part_events_within(&self, start: u64, end: u64, owned: impl Fn(usize) -> bool) -> Vec<Event> {
+        let mut out: Vec<Event> = self.mutator_pool.mutators.borrow()
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| owned(*idx))
+            .flat_map(|(idx, mutator)| mutator.create_events_ctx(start, end, idx, &self.asset_pool))
+            .collect();
+
+        out.extend(self.events
+            .iter()
+            .filter(|event| event.time_pos >= start && event.time_pos <= end)
+            .map(|event| Event::new(event.time_pos, event.mutator_idx, event.asset_idx)));
+
+        out
+    }
+
+    fn events_within(&self, start: u64, end: u64) -> Vec<Event> {
+        let mut out = self.mutator_pool.create_all_events_ctx(start, end, &self.asset_pool);
+
+        out.extend(self.events
+            .iter()
+            .filter(|event| event.time_pos >= start && event.time_pos <= end)
+            .map(|event| Event::new(event.time_pos, event.mutator_idx, event.asset_idx)));
+
+        out
+    }
+
+    /// Counts the events `events_within` would create, without creating them.
+    fn event_count(&self, start: u64, end: u64) -> usize {
+        let created = self.mutator_pool.mutators.borrow()
+            .iter()
+            .filter(|mutator| mutator.is_active())
+            .fold(0, |accum, mutator| accum + mutator.event_count(start, end) as usize);
+
+        created + self.events
+            .iter()
+            .filter(|event| event.time_pos >= start && event.time_pos <= end)
+            .count()
+    }
+
+    /// Captured values are restored as they were, bypassing the
+    /// `MutationPolicy` and scale of the `AssetPool`.
+    fn restore_memento(&self, memento: EventMemento) {
+        memento.asset_captures.into_iter().for_each(|cap| {
+            self.asset_pool.replace(AssetId(cap.idx), cap.value);
+        });
+
+        self.mutator_pool.reset(memento.mutator_states);
+    }
+
+    fn memento(&self, time_pos: u64) -> EventMemento {
+        EventMemento { 
+            time_pos, 
+            asset_captures: self.asset_pool.capture(), 
+            mutator_states: self.mutator_pool.capture() 
+        }
+    }
+}